@@ -0,0 +1,67 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_2023_1::{
+    get_first_digit, get_first_numeric_digit, get_last_digit, get_last_numeric_digit, Calibration,
+};
+use std::hint::black_box;
+
+const INPUTS: [(&str, &str); 3] = [
+    ("example", include_str!("../test.txt")),
+    ("example-2", include_str!("../test2.txt")),
+    ("input", include_str!("../input.txt")),
+];
+
+/// Finds a digit in a line of the calibration document.
+type Extractor = fn(&str) -> Option<usize>;
+
+fn extractors(c: &mut Criterion) {
+    let extractors: [(&str, Extractor); 4] = [
+        ("get_first_numeric_digit", get_first_numeric_digit),
+        ("get_last_numeric_digit", get_last_numeric_digit),
+        ("get_first_digit", get_first_digit),
+        ("get_last_digit", get_last_digit),
+    ];
+    let mut group = c.benchmark_group("day-1");
+
+    for (name, input) in INPUTS {
+        for (extractor, f) in extractors {
+            group.bench_with_input(BenchmarkId::new(extractor, name), input, |b, input| {
+                b.iter(|| {
+                    input
+                        .lines()
+                        .filter_map(|line| f(black_box(line)))
+                        .sum::<usize>()
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn solution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-1");
+
+    for (name, input) in INPUTS {
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| Calibration::parse(black_box(input)).unwrap())
+        });
+
+        // The first example has lines without digit characters, so part one
+        // fails on it; only time the parts that produce an answer.
+        let lines = Calibration::parse(input).unwrap();
+        if Calibration::part_one(&lines).is_ok() {
+            group.bench_with_input(BenchmarkId::new("part_one", name), &lines, |b, lines| {
+                b.iter(|| Calibration::part_one(black_box(lines)).unwrap())
+            });
+        }
+        group.bench_with_input(BenchmarkId::new("part_two", name), &lines, |b, lines| {
+            b.iter(|| Calibration::part_two(black_box(lines)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, extractors, solution);
+criterion_main!(benches);
@@ -0,0 +1,201 @@
+//! Day 1: Trebuchet?!
+
+use aoc_common::{generate::Rng, input, trace, AocError, Solution, Streaming};
+use std::io::BufRead;
+
+/// The digit extractor for the trebuchet calibration document.
+pub struct Calibration;
+impl Solution for Calibration {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Vec<String>> {
+        input::parse_lines(input, |_, line| {
+            trace!("read line: {line}");
+            Ok(line.to_string())
+        })
+    }
+    fn part_one(lines: &Vec<String>) -> aoc_common::Result<usize> {
+        calibration_sum(lines, get_first_numeric_digit, get_last_numeric_digit)
+    }
+    fn part_two(lines: &Vec<String>) -> aoc_common::Result<usize> {
+        calibration_sum(lines, get_first_digit, get_last_digit)
+    }
+}
+
+impl Streaming for Calibration {
+    fn stream_part_one(input: &mut dyn BufRead) -> aoc_common::Result<usize> {
+        input::fold_lines(input, 0, |sum, _, line| {
+            Ok(sum + calibration_value(line, get_first_numeric_digit, get_last_numeric_digit)?)
+        })
+    }
+    fn stream_part_two(input: &mut dyn BufRead) -> aoc_common::Result<usize> {
+        input::fold_lines(input, 0, |sum, _, line| {
+            Ok(sum + calibration_value(line, get_first_digit, get_last_digit)?)
+        })
+    }
+}
+
+fn calibration_sum(
+    lines: &[String],
+    first: fn(&str) -> Option<usize>,
+    last: fn(&str) -> Option<usize>,
+) -> aoc_common::Result<usize> {
+    let mut sum = 0;
+
+    for (i, line) in lines.iter().enumerate() {
+        sum += calibration_value(line, first, last).map_err(|e| e.at_line(i + 1))?;
+    }
+
+    Ok(sum)
+}
+
+/// The number made of the first and last digit on a line.
+fn calibration_value(
+    line: &str,
+    first: fn(&str) -> Option<usize>,
+    last: fn(&str) -> Option<usize>,
+) -> aoc_common::Result<usize> {
+    match (first(line), last(line)) {
+        (Some(first), Some(last)) => {
+            trace!("{line}\t{first}{last}");
+            Ok(first * 10 + last)
+        }
+        _ => Err(AocError::at_column(1, "no digit found")
+            .with_hint("every line needs at least one digit")),
+    }
+}
+
+/// Find the first digit character in `s`.
+pub fn get_first_numeric_digit(s: &str) -> Option<usize> {
+    s.chars().find_map(|c| c.to_digit(10)).map(|d| d as usize)
+}
+
+/// Find the last digit character in `s`.
+pub fn get_last_numeric_digit(s: &str) -> Option<usize> {
+    s.chars()
+        .rev()
+        .find_map(|c| c.to_digit(10))
+        .map(|d| d as usize)
+}
+
+/// Find the first digit in `s`, either as a character or spelled out.
+pub fn get_first_digit(s: &str) -> Option<usize> {
+    let mut chars = s.chars();
+
+    while !chars.as_str().is_empty() {
+        let s = chars.as_str();
+
+        if s.starts_with('0') || s.starts_with("zero") {
+            return Some(0);
+        } else if s.starts_with('1') || s.starts_with("one") {
+            return Some(1);
+        } else if s.starts_with('2') || s.starts_with("two") {
+            return Some(2);
+        } else if s.starts_with('3') || s.starts_with("three") {
+            return Some(3);
+        } else if s.starts_with('4') || s.starts_with("four") {
+            return Some(4);
+        } else if s.starts_with('5') || s.starts_with("five") {
+            return Some(5);
+        } else if s.starts_with('6') || s.starts_with("six") {
+            return Some(6);
+        } else if s.starts_with('7') || s.starts_with("seven") {
+            return Some(7);
+        } else if s.starts_with('8') || s.starts_with("eight") {
+            return Some(8);
+        } else if s.starts_with('9') || s.starts_with("nine") {
+            return Some(9);
+        }
+
+        let _ = chars.next();
+    }
+
+    None
+}
+
+/// Find the last digit in `s`, either as a character or spelled out.
+pub fn get_last_digit(s: &str) -> Option<usize> {
+    let mut chars = s.chars();
+
+    while !chars.as_str().is_empty() {
+        let s = chars.as_str();
+
+        if s.ends_with('0') || s.ends_with("zero") {
+            return Some(0);
+        } else if s.ends_with('1') || s.ends_with("one") {
+            return Some(1);
+        } else if s.ends_with('2') || s.ends_with("two") {
+            return Some(2);
+        } else if s.ends_with('3') || s.ends_with("three") {
+            return Some(3);
+        } else if s.ends_with('4') || s.ends_with("four") {
+            return Some(4);
+        } else if s.ends_with('5') || s.ends_with("five") {
+            return Some(5);
+        } else if s.ends_with('6') || s.ends_with("six") {
+            return Some(6);
+        } else if s.ends_with('7') || s.ends_with("seven") {
+            return Some(7);
+        } else if s.ends_with('8') || s.ends_with("eight") {
+            return Some(8);
+        } else if s.ends_with('9') || s.ends_with("nine") {
+            return Some(9);
+        }
+
+        let _ = chars.next_back();
+    }
+
+    None
+}
+
+const DIGIT_WORDS: &[&str] = &[
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+/// Digit words that share letters, which trip up extractors that skip past a
+/// whole word once they've found it.
+const OVERLAPPING_WORDS: &[&str] = &[
+    "oneight",
+    "twone",
+    "threeight",
+    "fiveight",
+    "sevenine",
+    "eightwo",
+    "eighthree",
+    "nineight",
+];
+
+/// Generate a calibration document of `lines` lines, each up to `width`
+/// characters of digits, digit words (often overlapping) and other letters.
+pub fn generate(seed: u64, lines: usize, width: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut document = String::new();
+
+    for _ in 0..lines {
+        let length = rng.range(1..width.max(1) + 1);
+        let mut line = String::new();
+        while line.len() < length {
+            match rng.range(0..10) {
+                0..=1 => line.push(char::from(b'0' + rng.range(0..10) as u8)),
+                2..=4 => line.push_str(rng.pick::<&str>(OVERLAPPING_WORDS)),
+                5..=6 => line.push_str(rng.pick::<&str>(DIGIT_WORDS)),
+                _ => line.push(char::from(b'a' + rng.range(0..26) as u8)),
+            }
+        }
+        line.truncate(length);
+
+        // Part one needs a digit character on every line.
+        if !line.bytes().any(|b| b.is_ascii_digit()) {
+            let digit = char::from(b'1' + rng.range(0..9) as u8);
+            let at = rng.range(0..length);
+            line.replace_range(at..=at, &digit.to_string());
+        }
+        document.push_str(&line);
+        document.push('\n');
+    }
+
+    document
+}
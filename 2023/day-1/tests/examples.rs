@@ -0,0 +1,28 @@
+use aoc_common::{input::normalize, Day, Part};
+use day_2023_1::Calibration;
+
+#[test]
+fn examples() {
+    aoc_common::expected::assert_examples::<Calibration>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn streamed_examples() {
+    aoc_common::expected::assert_streamed_examples::<Calibration>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn windows_line_endings() {
+    let input = include_str!("../test.txt").trim_end().replace('\n', "\r\n");
+    let answer = |input: &str| {
+        let answers = Day::of::<Calibration>()
+            .run(&normalize(input).unwrap(), Some(Part::Two))
+            .unwrap();
+        answers.part_two.unwrap().unwrap()
+    };
+
+    assert_eq!(answer(&input), "281");
+    assert_eq!(answer(&format!("\u{feff}{input}")), "281");
+    assert!(normalize("1abc2\ntwo1n\u{ed}ne\n").is_err());
+    assert!(normalize("1abc2\rtwo1nine\r\n").is_err());
+}
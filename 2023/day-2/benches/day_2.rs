@@ -0,0 +1,46 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_2023_2::Game;
+use std::hint::black_box;
+
+const INPUTS: [(&str, &str); 2] = [
+    ("example", include_str!("../test.txt")),
+    ("input", include_str!("../input.txt")),
+];
+
+fn game_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-2");
+
+    for (name, input) in INPUTS {
+        group.bench_with_input(BenchmarkId::new("Game::parse", name), input, |b, input| {
+            b.iter(|| {
+                input
+                    .lines()
+                    .map(|line| Game::parse(black_box(line)))
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn solution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-2");
+
+    for (name, input) in INPUTS {
+        let games = <Game as Solution>::parse(input).unwrap();
+        group.bench_with_input(BenchmarkId::new("part_one", name), &games, |b, games| {
+            b.iter(|| Game::part_one(black_box(games)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("part_two", name), &games, |b, games| {
+            b.iter(|| Game::part_two(black_box(games)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, game_parse, solution);
+criterion_main!(benches);
@@ -0,0 +1,248 @@
+//! Day 2: Cube Conundrum
+
+use aoc_common::{
+    checked_sum,
+    generate::Rng,
+    input,
+    parse::{self, expect, header, quoting, unsigned, Error, IResult},
+    trace, AocError, Solution, Streaming,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, space0, space1},
+    combinator::{consumed, cut, value},
+    error::context,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+};
+use std::{collections::HashMap, fmt, io::BufRead};
+
+impl Solution for Game {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Vec<Game>> {
+        input::parse_lines(input, |_, line| {
+            trace!("read line: {line}");
+            let game = Game::parse(line)?;
+            trace!("\t{game:?}");
+            Ok(game)
+        })
+    }
+    fn part_one(games: &Vec<Game>) -> aoc_common::Result<usize> {
+        checked_sum(
+            games
+                .iter()
+                .filter(|game| game.is_possible(12, 13, 14))
+                .map(|game| game.id),
+        )
+    }
+    fn part_two(games: &Vec<Game>) -> aoc_common::Result<usize> {
+        let powers = games.iter().map(Game::power);
+        checked_sum(powers.collect::<aoc_common::Result<Vec<_>>>()?)
+    }
+}
+
+impl Streaming for Game {
+    fn stream_part_one(input: &mut dyn BufRead) -> aoc_common::Result<usize> {
+        input::fold_lines(input, 0, |sum, _, line| {
+            let game = Game::parse(line)?;
+            if game.is_possible(12, 13, 14) {
+                checked_sum([sum, game.id])
+            } else {
+                Ok(sum)
+            }
+        })
+    }
+    fn stream_part_two(input: &mut dyn BufRead) -> aoc_common::Result<usize> {
+        input::fold_lines(input, 0, |sum, _, line| {
+            checked_sum([sum, Game::parse(line)?.power()?])
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Cube {
+    Red,
+    Green,
+    Blue,
+}
+impl Cube {
+    pub fn parse(input: &str) -> IResult<'_, Cube> {
+        context(
+            "expected color red/green/blue",
+            quoting(
+                "unknown color",
+                alt((
+                    value(Cube::Red, tag("red")),
+                    value(Cube::Green, tag("green")),
+                    value(Cube::Blue, tag("blue")),
+                )),
+            ),
+        )(input)
+    }
+}
+
+impl fmt::Display for Cube {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cube::Red => write!(f, "red"),
+            Cube::Green => write!(f, "green"),
+            Cube::Blue => write!(f, "blue"),
+        }
+    }
+}
+
+/// The cubes revealed from the bag at one time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Handful {
+    pub cubes: HashMap<Cube, usize>,
+}
+impl Handful {
+    pub fn parse(input: &str) -> Result<Handful, AocError> {
+        parse::finish(input, Handful::parser)
+    }
+    fn parser(input: &str) -> IResult<'_, Handful> {
+        // Each "3 red" or "30 blue", with the text it came from.
+        let (rest, counts) = separated_list1(
+            tuple((space0, char(','), space0)),
+            cut(consumed(context(
+                "cubes are written like \"3 blue\"",
+                separated_pair(
+                    expect("expected a count and a color", unsigned::<usize>),
+                    space1,
+                    Cube::parse,
+                ),
+            ))),
+        )(input)?;
+
+        let mut cubes: HashMap<Cube, usize> = HashMap::new();
+        for (text, (count, cube)) in counts {
+            let total = cubes.entry(cube).or_insert(0);
+            *total = (*total)
+                .checked_add(count)
+                .ok_or_else(|| nom::Err::Failure(Error::new(text, "too many cubes")))?;
+        }
+
+        Ok((rest, Handful { cubes }))
+    }
+    pub fn total(&self) -> usize {
+        self.reds() + self.greens() + self.blues()
+    }
+    pub fn reds(&self) -> usize {
+        *self.cubes.get(&Cube::Red).unwrap_or(&0)
+    }
+    pub fn greens(&self) -> usize {
+        *self.cubes.get(&Cube::Green).unwrap_or(&0)
+    }
+    pub fn blues(&self) -> usize {
+        *self.cubes.get(&Cube::Blue).unwrap_or(&0)
+    }
+}
+
+/// Written like `3 blue, 4 red`, always listing red, then green, then blue.
+impl fmt::Display for Handful {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut cubes = [Cube::Red, Cube::Green, Cube::Blue]
+            .into_iter()
+            .filter_map(|cube| Some((self.cubes.get(&cube)?, cube)));
+
+        if let Some((count, cube)) = cubes.next() {
+            write!(f, "{count} {cube}")?;
+        }
+        for (count, cube) in cubes {
+            write!(f, ", {count} {cube}")?;
+        }
+        Ok(())
+    }
+}
+
+/// One game: its ID and every handful of cubes shown during it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: usize,
+    pub handfuls: Vec<Handful>,
+}
+impl Game {
+    pub fn parse(line: &str) -> Result<Game, AocError> {
+        parse::finish(line, Game::parser)
+    }
+    fn parser(input: &str) -> IResult<'_, Game> {
+        let (rest, id) = context(
+            "games look like \"Game 1: 3 blue, 4 red; 1 red\"",
+            header("Game"),
+        )(input)?;
+        let (rest, handfuls) = preceded(
+            space0,
+            separated_list1(tuple((space0, char(';'), space0)), cut(Handful::parser)),
+        )(rest)?;
+
+        Ok((rest, Game { id, handfuls }))
+    }
+    /// Whether every handful could have come from a bag holding the given cubes.
+    pub fn is_possible(&self, reds: usize, greens: usize, blues: usize) -> bool {
+        self.handfuls.iter().all(|handful| {
+            handful.reds() <= reds && handful.greens() <= greens && handful.blues() <= blues
+        })
+    }
+    /// The power of the smallest set of cubes this game could be played with.
+    pub fn power(&self) -> aoc_common::Result<usize> {
+        let mut max_red = 0;
+        let mut max_green = 0;
+        let mut max_blue = 0;
+
+        for handful in &self.handfuls {
+            max_red = max_red.max(handful.reds());
+            max_green = max_green.max(handful.greens());
+            max_blue = max_blue.max(handful.blues());
+        }
+
+        max_red
+            .checked_mul(max_green)
+            .and_then(|power| power.checked_mul(max_blue))
+            .ok_or_else(|| AocError::overflow(format!("game {}'s power", self.id)))
+    }
+}
+/// Written like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+        for (i, handful) in self.handfuls.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ";" };
+            write!(f, "{separator} {handful}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Generate `games` games, each with up to `handfuls` handfuls of up to 20
+/// cubes of each color.
+pub fn generate(seed: u64, games: usize, handfuls: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut record = String::new();
+
+    for id in 1..=games {
+        let handfuls = (0..rng.range(1..handfuls.max(1) + 1))
+            .map(|_| {
+                let mut colors = [Cube::Red, Cube::Green, Cube::Blue];
+                rng.shuffle(&mut colors);
+                let shown = rng.range(1..4);
+                Handful {
+                    cubes: colors[..shown]
+                        .iter()
+                        .map(|&cube| (cube, rng.range(1..21)))
+                        .collect(),
+                }
+            })
+            .collect();
+
+        record.push_str(&Game { id, handfuls }.to_string());
+        record.push('\n');
+    }
+
+    record
+}
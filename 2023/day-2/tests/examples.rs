@@ -0,0 +1,49 @@
+use aoc_common::Solution;
+use day_2023_2::Game;
+
+#[test]
+fn examples() {
+    aoc_common::expected::assert_examples::<Game>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn streamed_examples() {
+    aoc_common::expected::assert_streamed_examples::<Game>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn parse_errors_have_locations() {
+    let error = |line| Game::parse(line).unwrap_err().to_string();
+
+    assert_eq!(
+        error("Gam 1: 3 red"),
+        "line 1, column 1: expected \"Game \""
+    );
+    assert_eq!(
+        error("Game x: 3 red"),
+        "line 1, column 6: invalid game ID \"x\""
+    );
+    assert_eq!(
+        error("Game 1: 3 red; 4 purple"),
+        "line 1, column 18: unknown color \"purple\""
+    );
+    assert_eq!(
+        error("Game 1: 3 red, blue"),
+        "line 1, column 16: expected a count and a color"
+    );
+    assert_eq!(
+        error("Game 1: 3 red, 18446744073709551615 red"),
+        "line 1, column 16: too many cubes"
+    );
+}
+
+#[test]
+fn too_much_power_is_an_error() {
+    let games =
+        <Game as Solution>::parse("Game 1: 4294967296 red, 4294967296 blue, 1 green").unwrap();
+
+    assert_eq!(
+        Game::part_two(&games).unwrap_err().to_string(),
+        "no answer: game 1's power is too large"
+    );
+}
@@ -0,0 +1,42 @@
+use day_2023_2::{Cube, Game, Handful};
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+fn handful() -> impl Strategy<Value = Handful> {
+    let cube = prop_oneof![Just(Cube::Red), Just(Cube::Green), Just(Cube::Blue)];
+    proptest::collection::hash_map(cube, 0..1000usize, 1..=3).prop_map(|cubes| Handful { cubes })
+}
+
+fn game() -> impl Strategy<Value = Game> {
+    (0..10_000usize, proptest::collection::vec(handful(), 1..10))
+        .prop_map(|(id, handfuls)| Game { id, handfuls })
+}
+
+proptest! {
+    #[test]
+    fn games_round_trip(game in game()) {
+        prop_assert_eq!(Game::parse(&game.to_string()).unwrap(), game);
+    }
+
+    #[test]
+    fn handfuls_round_trip(handful in handful()) {
+        prop_assert_eq!(Handful::parse(&handful.to_string()).unwrap(), handful);
+    }
+}
+
+#[test]
+fn displays_like_the_puzzle() {
+    let game = Game {
+        id: 1,
+        handfuls: vec![
+            Handful {
+                cubes: HashMap::from([(Cube::Blue, 3), (Cube::Red, 4)]),
+            },
+            Handful {
+                cubes: HashMap::from([(Cube::Red, 1)]),
+            },
+        ],
+    };
+
+    assert_eq!(game.to_string(), "Game 1: 4 red, 3 blue; 1 red");
+}
@@ -0,0 +1,42 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_2023_3::Schematic;
+use std::hint::black_box;
+
+const INPUTS: [(&str, &str); 2] = [
+    ("example", include_str!("../test.txt")),
+    ("input", include_str!("../input.txt")),
+];
+
+fn schematic_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-3");
+
+    for (name, input) in INPUTS {
+        group.bench_with_input(
+            BenchmarkId::new("Schematic::parse", name),
+            input,
+            |b, input| b.iter(|| Schematic::parse(black_box(input)).unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+fn solution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-3");
+
+    for (name, input) in INPUTS {
+        let schematic = <Schematic as Solution>::parse(input).unwrap();
+        group.bench_with_input(BenchmarkId::new("part_one", name), &schematic, |b, s| {
+            b.iter(|| Schematic::part_one(black_box(s)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("part_two", name), &schematic, |b, s| {
+            b.iter(|| Schematic::part_two(black_box(s)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, schematic_parse, solution);
+criterion_main!(benches);
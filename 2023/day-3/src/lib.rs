@@ -0,0 +1,209 @@
+//! Day 3: Gear Ratios
+
+use aoc_common::{
+    checked_sum,
+    generate::Rng,
+    grid::{Grid, Point},
+    trace, AocError, Solution,
+};
+use std::fmt;
+
+impl Solution for Schematic {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Answer = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Schematic> {
+        let schematic = Schematic::parse(input)?;
+        trace!("numbers: {:?}", schematic.numbers);
+        Ok(schematic)
+    }
+    fn part_one(schematic: &Schematic) -> aoc_common::Result<usize> {
+        checked_sum(
+            schematic
+                .numbers
+                .iter()
+                .filter(|number| schematic.is_part_number(number))
+                .map(|number| number.value),
+        )
+    }
+    fn part_two(schematic: &Schematic) -> aoc_common::Result<usize> {
+        let owners = schematic.number_cells();
+
+        let ratios = schematic
+            .grid
+            .iter()
+            .filter(|(_, cell)| **cell == Cell::Symbol('*'))
+            .filter_map(
+                |(point, _)| match schematic.adjacent_numbers(&owners, point)[..] {
+                    [a, b] => Some(
+                        a.value
+                            .checked_mul(b.value)
+                            .ok_or_else(|| AocError::overflow("a gear ratio")),
+                    ),
+                    _ => None,
+                },
+            );
+        checked_sum(ratios.collect::<aoc_common::Result<Vec<_>>>()?)
+    }
+}
+
+/// One character of the engine schematic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Digit(u8),
+    /// Any punctuation other than `.`.
+    Symbol(char),
+}
+impl Cell {
+    pub fn parse(c: char) -> Result<Cell, AocError> {
+        match c {
+            '.' => Ok(Cell::Empty),
+            '0'..='9' => Ok(Cell::Digit(c as u8 - b'0')),
+            c if c.is_ascii_punctuation() => Ok(Cell::Symbol(c)),
+            c => Err(
+                AocError::at_column(1, format!("unexpected {c:?} in the schematic"))
+                    .with_hint("schematics are made of digits, symbols and \".\""),
+            ),
+        }
+    }
+}
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Empty => write!(f, "."),
+            Cell::Digit(d) => write!(f, "{d}"),
+            Cell::Symbol(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+/// The engine schematic, and the numbers written across its cells.
+#[derive(Clone, Debug)]
+pub struct Schematic {
+    pub grid: Grid<Cell>,
+    pub numbers: Vec<Number>,
+}
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Schematic, AocError> {
+        let grid = Grid::parse(input, Cell::parse)?;
+        let mut numbers = vec![];
+
+        for (y, row) in grid.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let start = x;
+                let mut value: usize = 0;
+                while let Some(Cell::Digit(d)) = row.get(x) {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(*d as usize))
+                        .ok_or_else(|| AocError::parse(y + 1, start + 1, "number is too large"))?;
+                    x += 1;
+                }
+
+                if x > start {
+                    numbers.push(Number {
+                        value,
+                        start: Point::new(start, y),
+                        len: x - start,
+                    });
+                } else {
+                    x += 1;
+                }
+            }
+        }
+
+        Ok(Schematic { grid, numbers })
+    }
+    /// Whether a symbol touches any digit of the number, including diagonally.
+    pub fn is_part_number(&self, number: &Number) -> bool {
+        number.points().any(|point| {
+            self.grid
+                .neighbours8(point)
+                .any(|p| matches!(self.grid[p], Cell::Symbol(_)))
+        })
+    }
+    /// Which number each cell is part of, as an index into `numbers`.
+    pub fn number_cells(&self) -> Grid<Option<usize>> {
+        let mut owners = self.grid.map(|_| None);
+        for (i, number) in self.numbers.iter().enumerate() {
+            for point in number.points() {
+                owners[point] = Some(i);
+            }
+        }
+        owners
+    }
+    /// The numbers touching `point`, including diagonally, given which number
+    /// each cell is part of.
+    pub fn adjacent_numbers(&self, owners: &Grid<Option<usize>>, point: Point) -> Vec<&Number> {
+        let mut adjacent: Vec<usize> = self
+            .grid
+            .neighbours8(point)
+            .filter_map(|p| owners[p])
+            .collect();
+        adjacent.sort();
+        adjacent.dedup();
+
+        adjacent.into_iter().map(|i| &self.numbers[i]).collect()
+    }
+}
+impl fmt::Display for Schematic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+/// A number in the schematic, located by its first digit.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Number {
+    pub value: usize,
+    pub start: Point,
+    /// How many digits it has.
+    pub len: usize,
+}
+impl Number {
+    /// The cells of each of its digits.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Point { x, y } = self.start;
+        (x..x + self.len).map(move |x| Point::new(x, y))
+    }
+}
+
+/// Generate a `width` by `height` schematic where each cell is a symbol with
+/// probability `density`, and the rest are numbers of up to three digits and
+/// `.`s.
+pub fn generate(seed: u64, width: usize, height: usize, density: f64) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/@%=&-";
+    let mut rng = Rng::new(seed);
+    let mut schematic = String::with_capacity((width + 1) * height);
+
+    for _ in 0..height {
+        let mut x = 0;
+        let mut after_number = false;
+        while x < width {
+            if rng.chance(density) {
+                schematic.push(char::from(*rng.pick(SYMBOLS)));
+                x += 1;
+                after_number = false;
+            } else if !after_number && rng.chance(0.3) {
+                let digits = rng.range(1..4).min(width - x) as u32;
+                let number = rng.range(10usize.pow(digits - 1)..10usize.pow(digits));
+                schematic.push_str(&number.to_string());
+                x += digits as usize;
+                // Another number straight after would run into this one.
+                after_number = true;
+            } else {
+                schematic.push('.');
+                x += 1;
+                after_number = false;
+            }
+        }
+        schematic.push('\n');
+    }
+
+    schematic
+}
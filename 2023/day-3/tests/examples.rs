@@ -0,0 +1,33 @@
+use aoc_common::{input::normalize, Day, Solution};
+use day_2023_3::Schematic;
+
+#[test]
+fn examples() {
+    aoc_common::expected::assert_examples::<Schematic>(env!("CARGO_MANIFEST_DIR"));
+}
+
+fn answers(input: &str) -> [String; 2] {
+    let input = normalize(input).unwrap();
+    let answers = Day::of::<Schematic>().run(&input, None).unwrap();
+    [answers.part_one, answers.part_two].map(|answer| answer.unwrap().unwrap())
+}
+
+#[test]
+fn numbers_at_the_end_of_a_line_or_the_file() {
+    // 12 ends its line and 34 ends the file, both next to the gear.
+    assert_eq!(answers("..12\n...*\n.34."), ["46", "408"]);
+    assert_eq!(answers("..12\n...*\n..34"), ["46", "408"]);
+    assert_eq!(answers("..12\n...*\n..34\n"), ["46", "408"]);
+    assert_eq!(answers("\u{feff}..12\r\n...*\r\n..34"), ["46", "408"]);
+}
+
+#[test]
+fn gear_ratios_too_large_are_an_error() {
+    let input = "4294967296*4294967296\n";
+    let schematic = <Schematic as Solution>::parse(input).unwrap();
+
+    assert_eq!(
+        Schematic::part_two(&schematic).unwrap_err().to_string(),
+        "no answer: a gear ratio is too large"
+    );
+}
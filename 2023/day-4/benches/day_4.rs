@@ -0,0 +1,68 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_2023_4::Card;
+use std::hint::black_box;
+
+const INPUTS: [(&str, &str); 2] = [
+    ("example", include_str!("../test.txt")),
+    ("input", include_str!("../input.txt")),
+];
+
+fn card_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-4");
+
+    for (name, input) in INPUTS {
+        group.bench_with_input(BenchmarkId::new("Card::parse", name), input, |b, input| {
+            b.iter(|| {
+                input
+                    .lines()
+                    .map(|line| Card::parse(black_box(line)))
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn matching_numbers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-4");
+
+    for (name, input) in INPUTS {
+        let cards = <Card as Solution>::parse(input).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("Card::matching_numbers", name),
+            &cards,
+            |b, cards| {
+                b.iter(|| {
+                    black_box(cards)
+                        .iter()
+                        .map(|card| card.matching_numbers().len())
+                        .sum::<usize>()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn solution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-4");
+
+    for (name, input) in INPUTS {
+        let cards = <Card as Solution>::parse(input).unwrap();
+        group.bench_with_input(BenchmarkId::new("part_one", name), &cards, |b, cards| {
+            b.iter(|| Card::part_one(black_box(cards)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("part_two", name), &cards, |b, cards| {
+            b.iter(|| Card::part_two(black_box(cards)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, card_parse, matching_numbers, solution);
+criterion_main!(benches);
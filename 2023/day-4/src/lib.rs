@@ -0,0 +1,229 @@
+//! Day 4: Scratchcards
+
+use aoc_common::{
+    checked_sum,
+    generate::Rng,
+    input,
+    parse::{self, expect, header, list, quoting, unsigned, Error, IResult},
+    trace, AocError, Solution, Streaming,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, space0},
+    combinator::{eof, peek},
+    error::context,
+    sequence::{preceded, terminated},
+    Parser,
+};
+use std::{collections::VecDeque, io::BufRead};
+
+impl Solution for Card {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Vec<Card>> {
+        input::parse_lines(input, |_, line| {
+            trace!("read line: {line}");
+            Card::parse(line)
+        })
+    }
+    fn part_one(cards: &Vec<Card>) -> aoc_common::Result<usize> {
+        let points = cards.iter().map(Card::points);
+        checked_sum(points.collect::<aoc_common::Result<Vec<_>>>()?)
+    }
+    fn part_two(cards: &Vec<Card>) -> aoc_common::Result<usize> {
+        let counts = card_counts(cards)?;
+        for (count, card) in counts.iter().zip(cards) {
+            trace!(
+                "{card:?}\n\tcount: {count}, matches: {}",
+                card.matching_numbers().len()
+            );
+        }
+
+        checked_sum(counts)
+    }
+}
+
+impl Streaming for Card {
+    fn stream_part_one(input: &mut dyn BufRead) -> aoc_common::Result<usize> {
+        input::fold_lines(input, 0, |sum, _, line| {
+            let points = Card::parse(line)?.points()?;
+            checked_sum([sum, points])
+        })
+    }
+    fn stream_part_two(input: &mut dyn BufRead) -> aoc_common::Result<usize> {
+        let mut copies = Copies::default();
+        input::fold_lines(input, 0, |sum, _, line| {
+            let card = Card::parse(line)?;
+            checked_sum([sum, copies.scratch(card.matching_numbers().len())?])
+        })
+    }
+}
+
+/// How many copies of each card you end up with once every card's matches
+/// have won copies of the cards below it.
+pub fn card_counts(cards: &[Card]) -> aoc_common::Result<Vec<usize>> {
+    let mut copies = Copies::default();
+    cards
+        .iter()
+        .map(|card| copies.scratch(card.matching_numbers().len()))
+        .collect()
+}
+
+/// The copies won of the cards below the one being scratched. A card only
+/// wins copies of as many cards as it has matches, so this only ever holds
+/// that many counts however many cards there are.
+#[derive(Clone, Debug, Default)]
+pub struct Copies {
+    pending: VecDeque<usize>,
+}
+impl Copies {
+    /// Scratch the next card, which has `matches` matching numbers, returning
+    /// how many copies of it you have.
+    ///
+    /// Copies won past the last card are never scratched, so they don't count.
+    pub fn scratch(&mut self, matches: usize) -> aoc_common::Result<usize> {
+        let too_many = || AocError::overflow("the number of copies");
+        let count = self
+            .pending
+            .pop_front()
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or_else(too_many)?;
+        if self.pending.len() < matches {
+            self.pending.resize(matches, 0);
+        }
+        for pending in self.pending.iter_mut().take(matches) {
+            *pending = pending.checked_add(count).ok_or_else(too_many)?;
+        }
+        Ok(count)
+    }
+}
+
+/// A scratchcard with its winning numbers and the numbers you have.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    pub id: usize,
+    pub winning_numbers: Vec<usize>,
+    pub actual_numbers: Vec<usize>,
+}
+impl Card {
+    pub fn parse(line: &str) -> Result<Card, AocError> {
+        parse::finish(line, Card::parser)
+    }
+    fn parser(input: &str) -> IResult<'_, Card> {
+        let (rest, id) = context(
+            "cards look like \"Card 1: 41 48 | 83 86 17\"",
+            header("Card"),
+        )(input)?;
+        let (rest, winning_numbers) = Card::numbers(rest, alt((tag("|"), eof)))?;
+        let (rest, _) = context(
+            "winning numbers go before the \"|\" and your numbers after it",
+            expect("expected a \"|\" between the numbers", char('|')),
+        )(rest)?;
+        let (rest, actual_numbers) = Card::numbers(rest, eof)?;
+
+        Ok((
+            rest,
+            Card {
+                id,
+                winning_numbers,
+                actual_numbers,
+            },
+        ))
+    }
+    /// A list of numbers, which must be followed by `end`: anything else
+    /// there is a bad number.
+    fn numbers<'a, T>(
+        input: &'a str,
+        end: impl Parser<&'a str, T, Error<'a>>,
+    ) -> IResult<'a, Vec<usize>> {
+        preceded(
+            space0,
+            terminated(
+                list(unsigned),
+                preceded(space0, peek(quoting("invalid number", end))),
+            ),
+        )(input)
+    }
+    /// One point for the first match, doubled for each match after that.
+    pub fn points(&self) -> aoc_common::Result<usize> {
+        match self.matching_numbers().len() {
+            0 => Ok(0),
+            n => u32::try_from(n - 1)
+                .ok()
+                .and_then(|n| 2usize.checked_pow(n))
+                .ok_or_else(|| AocError::overflow(format!("card {}'s points", self.id))),
+        }
+    }
+    pub fn matching_numbers(&self) -> Vec<usize> {
+        self.actual_numbers
+            .iter()
+            .filter(|n| self.winning_numbers.contains(n))
+            .copied()
+            .collect()
+    }
+}
+/// Written like `Card 1: 41 48 | 83 86  6`, with numbers right-aligned to two
+/// digits as in the puzzle.
+impl std::fmt::Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Card {}:", self.id)?;
+        for n in &self.winning_numbers {
+            write!(f, " {n:>2}")?;
+        }
+        write!(f, " |")?;
+        for n in &self.actual_numbers {
+            write!(f, " {n:>2}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Generate `cards` cards, each with `numbers` numbers and a third as many
+/// winning numbers.
+///
+/// Cards only win copies of cards in their own group of ten, like the real
+/// puzzle's copies die out, so the number of copies can't overflow however
+/// many cards there are.
+pub fn generate(seed: u64, cards: usize, numbers: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut table = String::new();
+    let numbers = numbers.max(1);
+    let winning = (numbers / 3).max(1);
+
+    for i in 0..cards {
+        let room = (9 - i % 10).min(cards - i - 1);
+        let matches = rng.range(0..room.min(winning).min(numbers) + 1);
+
+        // Pick distinct numbers, so each match is only counted once.
+        let mut used = std::collections::HashSet::new();
+        let mut distinct = |rng: &mut Rng| loop {
+            let n = rng.range(1..numbers * 10);
+            if used.insert(n) {
+                return n;
+            }
+        };
+        let winning_numbers: Vec<usize> = (0..winning).map(|_| distinct(&mut rng)).collect();
+        let mut actual_numbers: Vec<usize> = winning_numbers[..matches]
+            .iter()
+            .copied()
+            .chain((matches..numbers).map(|_| distinct(&mut rng)))
+            .collect();
+        rng.shuffle(&mut actual_numbers);
+
+        let card = Card {
+            id: i + 1,
+            winning_numbers,
+            actual_numbers,
+        };
+        table.push_str(&card.to_string());
+        table.push('\n');
+    }
+
+    table
+}
@@ -0,0 +1,72 @@
+use aoc_common::{Day, Part, Solution};
+use day_2023_4::Card;
+
+#[test]
+fn examples() {
+    aoc_common::expected::assert_examples::<Card>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn streamed_examples() {
+    aoc_common::expected::assert_streamed_examples::<Card>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn parse_errors_have_locations() {
+    let error = |line| Card::parse(line).unwrap_err().to_string();
+
+    assert_eq!(
+        error("Card 1: 1 2 3"),
+        "line 1, column 14: expected a \"|\" between the numbers"
+    );
+    assert_eq!(
+        error("Card 1: 1 2 | 3 -4"),
+        "line 1, column 17: invalid number \"-4\""
+    );
+    assert_eq!(
+        error("Card one: 1 | 2"),
+        "line 1, column 6: invalid card ID \"one\""
+    );
+}
+
+#[test]
+fn copies_stop_at_the_last_card() {
+    let cards = [
+        Card::parse("Card 1: 1 2 | 1 2").unwrap(),
+        Card::parse("Card 2: 3 4 | 3 4").unwrap(),
+    ];
+
+    assert_eq!(day_2023_4::card_counts(&cards).unwrap(), [1, 2]);
+}
+
+#[test]
+fn too_many_copies_is_an_error() {
+    // Each card doubles the copies of the next two, so they soon overflow.
+    let table: String = (1..=119)
+        .map(|id| format!("Card {id}: 1 2 | 1 2\n"))
+        .collect();
+    let cards = <Card as Solution>::parse(&table).unwrap();
+    let error = "no answer: the number of copies is too large";
+
+    assert_eq!(
+        day_2023_4::card_counts(&cards).unwrap_err().to_string(),
+        error
+    );
+    let streamed = Day::streaming::<Card>().stream(&mut table.as_bytes(), Part::Two);
+    // The running total overflows just before the copies do.
+    assert_eq!(
+        streamed.unwrap().unwrap_err().to_string(),
+        "no answer: the sum is too large"
+    );
+}
+
+#[test]
+fn too_many_points_is_an_error() {
+    let line = format!("Card 1: 1 |{}", " 1".repeat(70));
+    let cards = <Card as Solution>::parse(&line).unwrap();
+
+    assert_eq!(
+        Card::part_one(&cards).unwrap_err().to_string(),
+        "no answer: card 1's points is too large"
+    );
+}
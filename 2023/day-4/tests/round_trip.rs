@@ -0,0 +1,28 @@
+use day_2023_4::Card;
+use proptest::prelude::*;
+
+fn card() -> impl Strategy<Value = Card> {
+    let numbers = || proptest::collection::vec(0..1000usize, 0..30);
+    (1..1000usize, numbers(), numbers()).prop_map(|(id, winning_numbers, actual_numbers)| Card {
+        id,
+        winning_numbers,
+        actual_numbers,
+    })
+}
+
+proptest! {
+    #[test]
+    fn cards_round_trip(card in card()) {
+        prop_assert_eq!(Card::parse(&card.to_string()).unwrap(), card);
+    }
+}
+
+#[test]
+fn displays_like_the_puzzle() {
+    let card = Card::parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+
+    assert_eq!(
+        card.to_string(),
+        "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
+    );
+}
@@ -0,0 +1,72 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_2023_5::Almanac;
+use rayon::prelude::*;
+use std::hint::black_box;
+
+const INPUTS: [(&str, &str); 2] = [
+    ("example", include_str!("../test.txt")),
+    ("input", include_str!("../input.txt")),
+];
+
+/// The real seed ranges cover billions of seeds, which takes minutes to map,
+/// so the benchmarks only map this many seeds from each range.
+const SEEDS_PER_RANGE: usize = 10_000;
+
+fn almanac_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-5");
+
+    for (name, input) in INPUTS {
+        group.bench_with_input(
+            BenchmarkId::new("Almanac::parse", name),
+            input,
+            |b, input| b.iter(|| Almanac::parse(black_box(input)).unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+fn mapped_seeds(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-5");
+
+    for (name, input) in INPUTS {
+        let mut almanac = <Almanac as Solution>::parse(input).unwrap();
+        for (_, count) in &mut almanac.seeds {
+            *count = (*count).min(SEEDS_PER_RANGE);
+        }
+
+        group.bench_with_input(
+            BenchmarkId::new("Almanac::mapped_seeds", name),
+            &almanac,
+            |b, almanac| {
+                b.iter(|| {
+                    black_box(almanac)
+                        .mapped_seeds()
+                        .map(|seed| seed.location)
+                        .min()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn solution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-5");
+
+    for (name, input) in INPUTS {
+        let almanac = <Almanac as Solution>::parse(input).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("part_one", name),
+            &almanac,
+            |b, almanac| b.iter(|| Almanac::part_one(black_box(almanac)).unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, almanac_parse, mapped_seeds, solution);
+criterion_main!(benches);
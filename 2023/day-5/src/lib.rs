@@ -0,0 +1,467 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use aoc_common::{
+    generate::Rng,
+    parse::{
+        self, blank_line, key_value, lines, list, quoting, sections, unsigned, Error, IResult,
+    },
+    trace, AocError, Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{line_ending, multispace0, space0, space1},
+    combinator::{map_res, peek, verify},
+    error::context,
+    sequence::{delimited, preceded, separated_pair},
+};
+use rayon::prelude::*;
+use std::fmt;
+
+impl Solution for Almanac {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Answer = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Almanac> {
+        trace!("{input}");
+        let almanac = parse::finish(input, Almanac::parse)?;
+        trace!("almanac: {almanac:?}");
+        Ok(almanac)
+    }
+    fn part_one(almanac: &Almanac) -> aoc_common::Result<usize> {
+        almanac
+            .seed_numbers()
+            .map(|seed| almanac.map_seed(seed).location)
+            .min()
+            .ok_or_else(|| AocError::NoAnswer("no seeds in almanac".into()))
+    }
+    fn part_two(almanac: &Almanac) -> aoc_common::Result<usize> {
+        almanac
+            .mapped_seeds()
+            .map(|s| s.location)
+            .min()
+            .ok_or_else(|| AocError::NoAnswer("no seeds in almanac".into()))
+    }
+}
+
+/// A failure for an almanac that is missing one of its maps.
+fn missing_map<'a>(input: &'a str, message: &'static str) -> nom::Err<Error<'a>> {
+    nom::Err::Failure(
+        Error::new(input, message)
+            .with_hint("an almanac needs every map from seed-to-soil to humidity-to-location"),
+    )
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Item {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+impl Item {
+    pub fn parse(input: &str) -> IResult<'_, Item> {
+        context(
+            "expected an item, like \"seed\" or \"soil\"",
+            map_res(
+                alt((
+                    tag("seed"),
+                    tag("soil"),
+                    tag("fertilizer"),
+                    tag("water"),
+                    tag("light"),
+                    tag("temperature"),
+                    tag("humidity"),
+                    tag("location"),
+                )),
+                |item: &str| match item {
+                    "seed" => Ok(Item::Seed),
+                    "soil" => Ok(Item::Soil),
+                    "fertilizer" => Ok(Item::Fertilizer),
+                    "water" => Ok(Item::Water),
+                    "light" => Ok(Item::Light),
+                    "temperature" => Ok(Item::Temperature),
+                    "humidity" => Ok(Item::Humidity),
+                    "location" => Ok(Item::Location),
+                    _ => Err(()),
+                },
+            ),
+        )(input)
+    }
+}
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Item::Seed => "seed",
+            Item::Soil => "soil",
+            Item::Fertilizer => "fertilizer",
+            Item::Water => "water",
+            Item::Light => "light",
+            Item::Temperature => "temperature",
+            Item::Humidity => "humidity",
+            Item::Location => "location",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The seeds to plant and the maps from each seed to its location.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Almanac {
+    /// The seeds, as `(start, count)` pairs.
+    pub seeds: Vec<(usize, usize)>,
+    pub seed_soil_map: Map,
+    pub soil_fertilizer_map: Map,
+    pub fertilizer_water_map: Map,
+    pub water_light_map: Map,
+    pub light_temperature_map: Map,
+    pub temperature_humidity_map: Map,
+    pub humidity_location_map: Map,
+}
+impl Almanac {
+    /// Every seed number covered by the seed ranges.
+    pub fn seeds(&self) -> impl ParallelIterator<Item = usize> + '_ {
+        self.seeds
+            .par_iter()
+            .flat_map(|(start, count)| *start..start + count)
+    }
+    pub fn mapped_seeds(&self) -> impl ParallelIterator<Item = MappedSeed> + '_ {
+        self.seeds().map(|seed| self.map_seed(seed))
+    }
+    /// The seed numbers, read as a plain list instead of as ranges.
+    pub fn seed_numbers(&self) -> impl Iterator<Item = usize> + '_ {
+        self.seeds.iter().flat_map(|&(start, count)| [start, count])
+    }
+    /// Follow a seed through every map to its location.
+    pub fn map_seed(&self, seed: usize) -> MappedSeed {
+        let soil = self.seed_soil_map.map(Item::Seed, seed);
+        let fertilizer = self.soil_fertilizer_map.map(Item::Soil, soil);
+        let water = self.fertilizer_water_map.map(Item::Fertilizer, fertilizer);
+        let light = self.water_light_map.map(Item::Water, water);
+        let temperature = self.light_temperature_map.map(Item::Light, light);
+        let humidity = self
+            .temperature_humidity_map
+            .map(Item::Temperature, temperature);
+        let location = self.humidity_location_map.map(Item::Humidity, humidity);
+
+        MappedSeed {
+            seed,
+            soil,
+            fertilizer,
+            water,
+            light,
+            temperature,
+            humidity,
+            location,
+        }
+    }
+    pub fn parse(input: &str) -> IResult<'_, Almanac> {
+        let mut seed_soil_map: Option<Map> = None;
+        let mut soil_fertilizer_map: Option<Map> = None;
+        let mut fertilizer_water_map: Option<Map> = None;
+        let mut water_light_map: Option<Map> = None;
+        let mut light_temperature_map: Option<Map> = None;
+        let mut temperature_humidity_map: Option<Map> = None;
+        let mut humidity_location_map: Option<Map> = None;
+
+        let (input, seeds) = context(
+            "an almanac starts with its seeds, like \"seeds: 79 14 55 13\"",
+            preceded(multispace0, Almanac::parse_seeds),
+        )(input)?;
+        let (input, _) = context("expected a blank line before the maps", blank_line)(input)?;
+        let (input, maps) = context(
+            "maps start with a header, like \"seed-to-soil map:\"",
+            sections(Map::section),
+        )(input)?;
+        let (input, _) = multispace0(input)?;
+        if !input.is_empty() {
+            // Anything left over should have been another map, so report why
+            // it isn't one.
+            return Err(nom::Err::Failure(match Map::parse(input) {
+                Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e,
+                _ => Error::new(input, "expected a blank line before this map"),
+            }));
+        }
+
+        for mut map in maps.into_iter() {
+            map.items.sort();
+
+            if map.items == [Item::Seed, Item::Soil] {
+                seed_soil_map = Some(map);
+            } else if map.items == [Item::Soil, Item::Fertilizer] {
+                soil_fertilizer_map = Some(map);
+            } else if map.items == [Item::Fertilizer, Item::Water] {
+                fertilizer_water_map = Some(map);
+            } else if map.items == [Item::Water, Item::Light] {
+                water_light_map = Some(map);
+            } else if map.items == [Item::Light, Item::Temperature] {
+                light_temperature_map = Some(map);
+            } else if map.items == [Item::Temperature, Item::Humidity] {
+                temperature_humidity_map = Some(map);
+            } else if map.items == [Item::Humidity, Item::Location] {
+                humidity_location_map = Some(map);
+            }
+        }
+
+        let almanac = Almanac {
+            seeds,
+            seed_soil_map: seed_soil_map
+                .ok_or_else(|| missing_map(input, "missing the seed-to-soil map"))?,
+            soil_fertilizer_map: soil_fertilizer_map
+                .ok_or_else(|| missing_map(input, "missing the soil-to-fertilizer map"))?,
+            fertilizer_water_map: fertilizer_water_map
+                .ok_or_else(|| missing_map(input, "missing the fertilizer-to-water map"))?,
+            water_light_map: water_light_map
+                .ok_or_else(|| missing_map(input, "missing the water-to-light map"))?,
+            light_temperature_map: light_temperature_map
+                .ok_or_else(|| missing_map(input, "missing the light-to-temperature map"))?,
+            temperature_humidity_map: temperature_humidity_map
+                .ok_or_else(|| missing_map(input, "missing the temperature-to-humidity map"))?,
+            humidity_location_map: humidity_location_map
+                .ok_or_else(|| missing_map(input, "missing the humidity-to-location map"))?,
+        };
+
+        Ok((input, almanac))
+    }
+    fn parse_seeds(input: &str) -> IResult<'_, Vec<(usize, usize)>> {
+        let (input, (_, seeds)) = key_value(
+            context("expected \"seeds\"", tag("seeds")),
+            list(verify(
+                separated_pair(unsigned, space1, unsigned),
+                |(start, count): &(usize, usize)| start.checked_add(*count).is_some(),
+            )),
+        )(input)?;
+        // The seeds go on one line, in pairs.
+        let (input, _) = preceded(space0, peek(quoting("invalid seed range", line_ending)))(input)?;
+        Ok((input, seeds))
+    }
+}
+/// Written as in the puzzle: the seeds, then each map after a blank line.
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds:")?;
+        for n in self.seed_numbers() {
+            write!(f, " {n}")?;
+        }
+        writeln!(f)?;
+
+        for map in [
+            &self.seed_soil_map,
+            &self.soil_fertilizer_map,
+            &self.fertilizer_water_map,
+            &self.water_light_map,
+            &self.light_temperature_map,
+            &self.temperature_humidity_map,
+            &self.humidity_location_map,
+        ] {
+            write!(f, "\n{map}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A seed and every item it maps to on the way to its location.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MappedSeed {
+    pub seed: usize,
+    pub soil: usize,
+    pub fertilizer: usize,
+    pub water: usize,
+    pub light: usize,
+    pub temperature: usize,
+    pub humidity: usize,
+    pub location: usize,
+}
+
+/// A map between two kinds of item, made up of ranges that map one to the other.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Map {
+    pub items: [Item; 2],
+    pub ranges: Vec<MapRange>,
+}
+impl Map {
+    /// A map, with any whitespace around it.
+    pub fn parse(input: &str) -> IResult<'_, Map> {
+        delimited(multispace0, Map::section, multispace0)(input)
+    }
+    /// A map's header and ranges, ending at the end of its last range.
+    fn section(input: &str) -> IResult<'_, Map> {
+        let (input, item0) = Item::parse(input)?;
+        let (input, _) = context("expected \"-to-\"", tag("-to-"))(input)?;
+        let (input, item1) = Item::parse(input)?;
+        let (input, _) = context("expected \" map:\"", tag(" map:"))(input)?;
+        let (input, _) = context("expected a new line", line_ending)(input)?;
+        let (input, ranges) = context(
+            "map ranges are three numbers, like \"50 98 2\"",
+            lines(MapRange::parse),
+        )(input)?;
+
+        Ok((
+            input,
+            Map {
+                items: [item0, item1],
+                ranges,
+            },
+        ))
+    }
+    pub fn can_map(&self, item: Item) -> bool {
+        self.items.contains(&item)
+    }
+    /// Map `index` from `item` to the other item in this map. Indices not
+    /// covered by any range map to themselves.
+    pub fn map(&self, item: Item, index: usize) -> usize {
+        if self.items[0] == item {
+            for range in &self.ranges {
+                if let Some(index) = range.map_forward(index) {
+                    return index;
+                }
+            }
+        } else if self.items[1] == item {
+            for range in &self.ranges {
+                if let Some(index) = range.map_back(index) {
+                    return index;
+                }
+            }
+        }
+
+        index
+    }
+}
+/// Written like `seed-to-soil map:` followed by a line per range.
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}-to-{} map:", self.items[0], self.items[1])?;
+        for range in &self.ranges {
+            writeln!(f, "{range}")?;
+        }
+        Ok(())
+    }
+}
+
+/// `length` consecutive indices starting at `source_start`, mapped to the
+/// same number of indices starting at `destination_start`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MapRange {
+    pub source_start: usize,
+    pub destination_start: usize,
+    pub length: usize,
+}
+impl MapRange {
+    pub fn parse(input: &str) -> IResult<'_, MapRange> {
+        let start = input;
+        let (input, destination_start) = unsigned::<usize>(input)?;
+        let (input, _) = space1(input)?;
+        let (input, source_start) = unsigned::<usize>(input)?;
+        let (input, _) = space1(input)?;
+        let (input, length) = unsigned::<usize>(input)?;
+        if source_start.checked_add(length).is_none()
+            || destination_start.checked_add(length).is_none()
+        {
+            return Err(nom::Err::Failure(Error::new(
+                start,
+                "map range goes past the largest number",
+            )));
+        }
+
+        Ok((
+            input,
+            MapRange {
+                source_start,
+                destination_start,
+                length,
+            },
+        ))
+    }
+    pub fn map_forward(&self, index: usize) -> Option<usize> {
+        if self.source_start <= index && index < self.source_start + self.length {
+            Some(self.destination_start + (index - self.source_start))
+        } else {
+            None
+        }
+    }
+    pub fn map_back(&self, index: usize) -> Option<usize> {
+        if self.destination_start <= index && index < self.destination_start + self.length {
+            Some(self.source_start + (index - self.destination_start))
+        } else {
+            None
+        }
+    }
+}
+/// Written like `50 98 2`: the destination start, source start and length.
+impl fmt::Display for MapRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.destination_start, self.source_start, self.length
+        )
+    }
+}
+
+/// Generate an almanac with `seeds` seed ranges of up to a billion seeds
+/// each, and maps of `ranges` ranges each.
+///
+/// Like the real puzzle's, each map's ranges don't overlap, and map numbers
+/// below 2^32 to numbers below 2^32.
+pub fn generate(seed: u64, seeds: usize, ranges: usize) -> String {
+    const LIMIT: usize = 1 << 32;
+    use Item::*;
+    let mut rng = Rng::new(seed);
+
+    let seeds = (0..seeds.max(1))
+        .map(|_| {
+            let start = rng.range(0..LIMIT);
+            (start, rng.range(1..(LIMIT - start).min(1_000_000_000) + 1))
+        })
+        .collect();
+
+    let mut map = |items| {
+        // Cut the numbers into pieces, then move the pieces around.
+        let mut cuts: Vec<usize> = (1..ranges.max(1)).map(|_| rng.range(1..LIMIT)).collect();
+        cuts.extend([0, LIMIT]);
+        cuts.sort();
+        cuts.dedup();
+        let sources: Vec<(usize, usize)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        let mut order: Vec<usize> = (0..sources.len()).collect();
+        rng.shuffle(&mut order);
+
+        let mut destination_start = 0;
+        let mut destinations = vec![0; sources.len()];
+        for i in order {
+            destinations[i] = destination_start;
+            destination_start += sources[i].1;
+        }
+
+        Map {
+            items,
+            ranges: sources
+                .into_iter()
+                .zip(destinations)
+                .map(|((source_start, length), destination_start)| MapRange {
+                    source_start,
+                    destination_start,
+                    length,
+                })
+                .collect(),
+        }
+    };
+
+    Almanac {
+        seeds,
+        seed_soil_map: map([Seed, Soil]),
+        soil_fertilizer_map: map([Soil, Fertilizer]),
+        fertilizer_water_map: map([Fertilizer, Water]),
+        water_light_map: map([Water, Light]),
+        light_temperature_map: map([Light, Temperature]),
+        temperature_humidity_map: map([Temperature, Humidity]),
+        humidity_location_map: map([Humidity, Location]),
+    }
+    .to_string()
+}
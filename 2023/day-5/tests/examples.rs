@@ -0,0 +1,62 @@
+use day_2023_5::{Almanac, Item};
+
+#[test]
+fn examples() {
+    aoc_common::expected::assert_examples::<Almanac>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn maps_without_seeds() {
+    let input = include_str!("../test2.txt");
+    let (rest, maps) = nom::multi::many1(day_2023_5::Map::parse)(input).unwrap();
+    assert!(rest.is_empty());
+
+    let [seed_soil, soil_seed] = &maps[..] else {
+        panic!("expected two maps, got {maps:?}");
+    };
+    assert_eq!(seed_soil.items, [Item::Seed, Item::Soil]);
+    assert_eq!(soil_seed.items, [Item::Soil, Item::Seed]);
+
+    // Mapping works in either direction, whichever way the map is written.
+    for seed in [0, 49, 50, 79, 98, 99, 100] {
+        let soil = seed_soil.map(Item::Seed, seed);
+        assert_eq!(soil_seed.map(Item::Seed, soil), seed);
+        assert_eq!(seed_soil.map(Item::Soil, soil), seed);
+    }
+    assert_eq!(seed_soil.map(Item::Seed, 79), 81);
+    assert_eq!(seed_soil.map(Item::Seed, 98), 50);
+}
+
+#[test]
+fn parse_errors_point_at_the_bad_token() {
+    let input = "seeds: 79 14\n\nseed-to-soil map:\n50 x 2\n";
+    let error = <Almanac as aoc_common::Solution>::parse(input).unwrap_err();
+
+    assert_eq!(
+        aoc_common::diagnostic::render(&error, input),
+        "error: expected a number\n \
+         --> line 4, column 4\n  \
+         |\n\
+         4 | 50 x 2\n  \
+         |    ^\n  \
+         = hint: map ranges are three numbers, like \"50 98 2\"\n"
+    );
+}
+
+#[test]
+fn ranges_past_the_largest_number_dont_parse() {
+    let error = |input| {
+        <Almanac as aoc_common::Solution>::parse(input)
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert_eq!(
+        error("seeds: 18446744073709551615 1\n\nseed-to-soil map:\n50 98 2\n"),
+        "line 1, column 8: invalid seed range \"18446744073709551615\""
+    );
+    assert_eq!(
+        error("seeds: 79 14\n\nseed-to-soil map:\n50 18446744073709551615 2\n"),
+        "line 4, column 1: map range goes past the largest number"
+    );
+}
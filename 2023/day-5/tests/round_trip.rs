@@ -0,0 +1,65 @@
+use aoc_common::Solution;
+use day_2023_5::{Almanac, Item, Map, MapRange};
+use proptest::prelude::*;
+
+/// Numbers big enough to need more than 32 bits, like the real inputs.
+const MAX: usize = 1 << 40;
+
+fn map_range() -> impl Strategy<Value = MapRange> {
+    (0..MAX, 0..MAX, 0..MAX).prop_map(|(destination_start, source_start, length)| MapRange {
+        source_start,
+        destination_start,
+        length,
+    })
+}
+
+fn map(items: [Item; 2]) -> impl Strategy<Value = Map> {
+    proptest::collection::vec(map_range(), 1..10).prop_map(move |ranges| Map { items, ranges })
+}
+
+fn almanac() -> impl Strategy<Value = Almanac> {
+    use Item::*;
+
+    let seeds = proptest::collection::vec((0..MAX, 0..MAX), 1..10);
+    let maps = (
+        map([Seed, Soil]),
+        map([Soil, Fertilizer]),
+        map([Fertilizer, Water]),
+        map([Water, Light]),
+        map([Light, Temperature]),
+        map([Temperature, Humidity]),
+        map([Humidity, Location]),
+    );
+    (seeds, maps).prop_map(|(seeds, maps)| Almanac {
+        seeds,
+        seed_soil_map: maps.0,
+        soil_fertilizer_map: maps.1,
+        fertilizer_water_map: maps.2,
+        water_light_map: maps.3,
+        light_temperature_map: maps.4,
+        temperature_humidity_map: maps.5,
+        humidity_location_map: maps.6,
+    })
+}
+
+proptest! {
+    #[test]
+    fn almanacs_round_trip(almanac in almanac()) {
+        let parsed = <Almanac as Solution>::parse(&almanac.to_string()).unwrap();
+        prop_assert_eq!(parsed, almanac);
+    }
+
+    #[test]
+    fn map_ranges_round_trip(range in map_range()) {
+        let text = range.to_string();
+        prop_assert_eq!(MapRange::parse(&text).unwrap(), ("", range));
+    }
+}
+
+#[test]
+fn displays_like_the_puzzle() {
+    let example = include_str!("../test.txt");
+    let almanac = <Almanac as Solution>::parse(example).unwrap();
+
+    assert_eq!(almanac.to_string().trim_end(), example.trim_end());
+}
@@ -0,0 +1,339 @@
+use crate::{
+    diagnostic, history, input,
+    log::{self, Level, LogArgs},
+    Answers, AocError, Day, Part, Report, Result, Solution, Streaming, Timings,
+};
+use clap::Parser;
+use std::{
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+/// Which input to run a day against and which parts to run, shared by
+/// everything that runs a day.
+#[derive(clap::Args, Clone, Debug)]
+pub struct InputArgs {
+    /// Read the input from this file, or from stdin if it's `-`. Defaults to
+    /// the day's input.txt.
+    #[arg(short, long, conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+    /// Use the day's example (test.txt), or another numbered example
+    /// (`--example 2` for test2.txt).
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "1", value_name = "N")]
+    pub example: Option<u8>,
+    /// Only run one part of the puzzle.
+    #[arg(long)]
+    pub part: Option<Part>,
+}
+
+/// Options for running a day, shared by each day's binary and `aoc run`.
+#[derive(clap::Args, Clone, Debug)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+    /// How to print the results.
+    #[arg(long, value_enum, default_value_t = Format::Human)]
+    pub format: Format,
+    /// Print how long reading, parsing and each part took.
+    #[arg(long)]
+    pub timings: bool,
+    /// Append the answers and timings to the run history.
+    #[arg(long)]
+    pub record: bool,
+    /// Solve each part in a single pass over the input instead of reading it
+    /// all into memory, for days that support it.
+    #[arg(long, conflicts_with = "record")]
+    pub stream: bool,
+}
+
+#[derive(clap::ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// One line per answer.
+    Human,
+    /// A single JSON object with the answers and timings.
+    Json,
+}
+
+#[derive(Parser, Debug)]
+struct DayArgs {
+    #[command(flatten)]
+    run: RunArgs,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+impl InputArgs {
+    pub fn source(&self) -> input::Source {
+        match (&self.input, self.example) {
+            (_, Some(n)) => input::Source::Example(n),
+            (Some(path), None) if path.as_os_str() == "-" => input::Source::Stdin,
+            (Some(path), None) => input::Source::File(path.clone()),
+            (None, None) => input::Source::Default,
+        }
+    }
+}
+
+/// The entire `main` of a day's binary: solve the day's input and print the
+/// answers.
+pub fn main<S: Solution>() -> ExitCode {
+    day_main(&Day::of::<S>())
+}
+
+/// Like [`main`], for a day that can also stream its input.
+pub fn main_streaming<S: Streaming>() -> ExitCode {
+    day_main(&Day::streaming::<S>())
+}
+
+fn day_main(day: &Day) -> ExitCode {
+    let args = DayArgs::parse();
+    args.log.init();
+    exit_code(run(day, &args.run))
+}
+
+/// Turn the result of a command into an exit code, printing the error if
+/// there was one. `Ok(false)` means the command failed but already explained
+/// why.
+pub fn exit_code(result: Result<bool>) -> ExitCode {
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run a day against the input chosen by `args` and print the results,
+/// returning whether every part succeeded.
+pub fn run(day: &Day, args: &RunArgs) -> Result<bool> {
+    let (success, timings) = solve(day, args)?;
+    if args.timings && args.format == Format::Human {
+        print!("{}", timing_table(&[(day, timings)]));
+    }
+    Ok(success)
+}
+
+/// Run a day against the input chosen by `args` and print its answers,
+/// returning whether every part succeeded and how long each phase took.
+pub fn solve(day: &Day, args: &RunArgs) -> Result<(bool, Timings)> {
+    if args.stream {
+        return stream(day, args);
+    }
+    let start = Instant::now();
+    let raw = args.input.source().read_raw(day.year, day.day)?;
+    let input = input::normalize(&raw);
+    let read = start.elapsed();
+    let failed = |e: &AocError, input: &str| {
+        eprint!("{}", diagnostic::render(e, input));
+        let timings = Timings {
+            read: Some(read),
+            ..Timings::default()
+        };
+        Ok((false, timings))
+    };
+
+    let input = match &input {
+        Ok(input) => input,
+        Err(e) => return failed(e, input::without_byte_order_mark(&raw)),
+    };
+    let mut answers = match day.run(input, args.input.part) {
+        Ok(answers) => answers,
+        Err(e) => return failed(&e, input),
+    };
+    answers.timings.read = Some(read);
+    let timings = answers.timings;
+    let record = args
+        .record
+        .then(|| history::Record::new(day, input, &answers));
+
+    let success = match args.format {
+        Format::Human => print_answers(answers, input),
+        Format::Json => print_json(day, answers, input),
+    };
+    if let Some(record) = record {
+        history::append(&history::path(), &record)?;
+    }
+    Ok((success, timings))
+}
+
+/// Like [`solve`], but streaming the input through each part in turn. Only
+/// the parts' timings are known, and they include reading the input.
+fn stream(day: &Day, args: &RunArgs) -> Result<(bool, Timings)> {
+    let source = args.input.source();
+    if source == input::Source::Stdin && args.input.part.is_none() {
+        return Err(AocError::Usage(
+            "stdin can only be streamed through one part; choose it with --part".into(),
+        ));
+    }
+
+    let mut answers = Answers::default();
+    for part in [Part::One, Part::Two] {
+        if args.input.part.is_some_and(|only| only != part) {
+            continue;
+        }
+        let mut reader = source.open(day.year, day.day)?;
+
+        let start = Instant::now();
+        let answer = day.stream(&mut reader, part).ok_or_else(|| {
+            AocError::Usage(format!(
+                "{} day {} can't stream its input",
+                day.year, day.day
+            ))
+        })?;
+        let elapsed = Some(start.elapsed());
+        match part {
+            Part::One => (answers.part_one, answers.timings.part1) = (Some(answer), elapsed),
+            Part::Two => (answers.part_two, answers.timings.part2) = (Some(answer), elapsed),
+        }
+    }
+
+    // There's no input in memory to show errors against, so they're shown
+    // without a snippet.
+    let timings = answers.timings;
+    let success = match args.format {
+        Format::Human => print_answers(answers, ""),
+        Format::Json => print_json(day, answers, ""),
+    };
+    Ok((success, timings))
+}
+
+/// A table of how long each phase took for each day, in milliseconds, with a
+/// total row when there's more than one day.
+pub fn timing_table(rows: &[(&Day, Timings)]) -> String {
+    let mut table = format!(
+        "{:<6} {:<4} {:>10} {:>10} {:>10} {:>10} {:>10}\n",
+        "year", "day", "read", "parse", "part 1", "part 2", "total"
+    );
+    for (day, timings) in rows {
+        table += &timing_row(&day.year.to_string(), &day.day.to_string(), timings);
+    }
+    if rows.len() > 1 {
+        let total = rows.iter().map(|(_, t)| *t).sum();
+        table += &timing_row("total", "", &total);
+    }
+    table
+}
+
+fn timing_row(year: &str, day: &str, timings: &Timings) -> String {
+    let ms = |d: Option<Duration>| match d {
+        Some(d) => format!("{:.3}", d.as_secs_f64() * 1000.0),
+        None => "-".into(),
+    };
+    format!(
+        "{year:<6} {day:<4} {:>10} {:>10} {:>10} {:>10} {:>10}\n",
+        ms(timings.read),
+        ms(timings.parse),
+        ms(timings.part1),
+        ms(timings.part2),
+        ms(Some(timings.total())),
+    )
+}
+
+/// Print each part's answer, with errors rendered against the input on
+/// stderr. Returns whether every part succeeded.
+///
+/// When quiet, the answers are printed without labels.
+pub fn print_answers(answers: Answers, input: &str) -> bool {
+    let mut success = true;
+
+    for (part, answer) in [(Part::One, answers.part_one), (Part::Two, answers.part_two)] {
+        match answer {
+            Some(Ok(answer)) if log::level() == Level::Quiet => println!("{answer}"),
+            Some(Ok(answer)) => println!("part {}: {answer}", part.number()),
+            Some(Err(e)) => {
+                eprint!(
+                    "part {} failed: {}",
+                    part.number(),
+                    diagnostic::render(&e, input)
+                );
+                success = false;
+            }
+            None => {}
+        }
+    }
+
+    success
+}
+
+/// Print the answers as a JSON [`Report`]. Failed parts are `null`, with the
+/// error printed to stderr. Returns whether every part succeeded.
+pub fn print_json(day: &Day, answers: Answers, input: &str) -> bool {
+    let (report, errors) = Report::new(day, answers);
+    println!(
+        "{}",
+        serde_json::to_string(&report).expect("reports are always valid JSON")
+    );
+
+    for (part, e) in &errors {
+        eprint!(
+            "part {} failed: {}",
+            part.number(),
+            diagnostic::render(e, input)
+        );
+    }
+    errors.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use input::Source;
+
+    fn source(args: &[&str]) -> Source {
+        let args = DayArgs::try_parse_from([&["day"], args].concat()).unwrap();
+        args.run.input.source()
+    }
+
+    #[test]
+    fn input_sources() {
+        assert_eq!(source(&[]), Source::Default);
+        assert_eq!(source(&["--example"]), Source::Example(1));
+        assert_eq!(source(&["--example", "2"]), Source::Example(2));
+        assert_eq!(source(&["--input", "-"]), Source::Stdin);
+        assert_eq!(source(&["-i", "big.txt"]), Source::File("big.txt".into()));
+        assert_eq!(source(&["--example", "--part", "2"]), Source::Example(1));
+        assert!(DayArgs::try_parse_from(["day", "--example", "--input", "a.txt"]).is_err());
+        assert!(DayArgs::try_parse_from(["day", "--stream", "--record"]).is_err());
+    }
+
+    #[test]
+    fn timing_tables() {
+        let day = Day::of::<Example>();
+        let ms = |n| Some(Duration::from_millis(n));
+        let timings = Timings {
+            read: ms(1),
+            parse: ms(2),
+            part1: ms(3),
+            part2: None,
+        };
+
+        assert_eq!(
+            timing_table(&[(&day, timings)]),
+            "year   day        read      parse     part 1     part 2      total\n\
+             2023   1         1.000      2.000      3.000          -      6.000\n"
+        );
+        assert!(timing_table(&[(&day, timings), (&day, timings)])
+            .ends_with("total            2.000      4.000      6.000          -     12.000\n"));
+    }
+
+    struct Example;
+    impl Solution for Example {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+
+        type Input = ();
+        type Answer = u8;
+
+        fn parse(_: &str) -> Result<()> {
+            Ok(())
+        }
+        fn part_one(_: &()) -> Result<u8> {
+            Ok(1)
+        }
+        fn part_two(_: &()) -> Result<u8> {
+            Ok(2)
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Rendering errors against the input they came from, so a parse error points
+//! at the exact token that caused it:
+//!
+//! ```text
+//! error: unknown color "purple"
+//!  --> line 2, column 19
+//!   |
+//! 2 | Game 2: 3 blue, 4 purple
+//!   |                   ^^^^^^
+//!   = hint: expected red, green or blue
+//! ```
+
+use crate::AocError;
+use std::fmt::Write;
+
+/// Render an error as a diagnostic. Parse errors get a snippet of the input
+/// with a caret under the bad token, and everything else is a single line.
+pub fn render(error: &AocError, input: &str) -> String {
+    let AocError::Parse {
+        line,
+        column,
+        message,
+        hint,
+    } = error
+    else {
+        return format!("error: {error}\n");
+    };
+
+    let mut out = format!("error: {message}\n");
+    let gutter = " ".repeat(line.to_string().len());
+    let _ = writeln!(out, "{gutter}--> line {line}, column {column}");
+
+    if let Some(text) = input.lines().nth(line.saturating_sub(1)) {
+        let (indent, width) = caret(text, column.saturating_sub(1));
+        let _ = writeln!(out, "{gutter} |");
+        let _ = writeln!(out, "{line} | {text}");
+        let _ = writeln!(
+            out,
+            "{gutter} | {}{}",
+            " ".repeat(indent),
+            "^".repeat(width)
+        );
+    }
+    if let Some(hint) = hint {
+        let _ = writeln!(out, "{gutter} = hint: {hint}");
+    }
+
+    out
+}
+
+/// Where to put the caret under `text` for an error at byte offset `offset`,
+/// and how wide it should be to cover the token there. Both are counted in
+/// characters so that the caret lines up.
+fn caret(text: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let indent = text[..offset].chars().count();
+    let width = text[offset..]
+        .chars()
+        .take_while(|c| !c.is_whitespace() && !",;:|".contains(*c))
+        .count();
+
+    (indent, width.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_caret_under_the_token() {
+        let input = "Game 1: 3 blue\nGame 2: 3 blue, 4 purple\n";
+        let error = AocError::parse(2, 19, "unknown color \"purple\"")
+            .with_hint("expected red, green or blue");
+
+        assert_eq!(
+            render(&error, input),
+            "error: unknown color \"purple\"\n \
+             --> line 2, column 19\n  \
+             |\n\
+             2 | Game 2: 3 blue, 4 purple\n  \
+             |                   ^^^^^^\n  \
+             = hint: expected red, green or blue\n"
+        );
+    }
+
+    #[test]
+    fn carets_at_the_end_of_a_line() {
+        assert_eq!(caret("Card 1: 1 2", 11), (11, 1));
+        assert_eq!(caret("Card 1: 1 2", 50), (11, 1));
+        assert_eq!(caret("x: 1", 0), (0, 1));
+    }
+
+    #[test]
+    fn other_errors_are_one_line() {
+        let error = AocError::NoAnswer("no seeds in almanac".into());
+        assert_eq!(
+            render(&error, ""),
+            "error: no answer: no seeds in almanac\n"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt;
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// Reading or writing a file failed.
+    Io(std::io::Error),
+    /// The puzzle input could not be parsed. Lines and columns start at 1,
+    /// and columns count bytes.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+        /// A suggestion for fixing the input, shown with the diagnostic.
+        hint: Option<String>,
+    },
+    /// The input parsed, but no answer could be produced from it.
+    NoAnswer(String),
+    /// There is no solution registered for the requested day.
+    Unimplemented { year: u16, day: u8 },
+    /// A request to the puzzle server failed.
+    Request(String),
+    /// What was asked for can't be done, like streaming stdin through both
+    /// parts.
+    Usage(String),
+}
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl fmt::Display) -> AocError {
+        AocError::Parse {
+            line,
+            column,
+            message: message.to_string(),
+            hint: None,
+        }
+    }
+    /// Add a hint to a parse error.
+    pub fn with_hint(mut self, hint: impl fmt::Display) -> AocError {
+        if let AocError::Parse { hint: h, .. } = &mut self {
+            *h = Some(hint.to_string());
+        }
+        self
+    }
+    /// An error in a parser that only sees a single line. The line number is
+    /// filled in later with [`AocError::at_line`], which
+    /// [`parse_lines`](crate::input::parse_lines) does automatically.
+    pub fn at_column(column: usize, message: impl fmt::Display) -> AocError {
+        AocError::parse(1, column, message)
+    }
+    /// Set the line a parse error happened on.
+    pub fn at_line(mut self, line: usize) -> AocError {
+        if let AocError::Parse { line: l, .. } = &mut self {
+            *l = line;
+        }
+        self
+    }
+    /// Move a parse error right by `offset` columns, for when a parser was
+    /// given a slice that starts partway through its line.
+    pub fn offset_columns(mut self, offset: usize) -> AocError {
+        if let AocError::Parse { column, .. } = &mut self {
+            *column += offset;
+        }
+        self
+    }
+    /// A failure for an answer, or a number it's built from, that doesn't
+    /// fit in its type.
+    pub fn overflow(what: impl fmt::Display) -> AocError {
+        AocError::NoAnswer(format!("{what} is too large"))
+    }
+}
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "{e}"),
+            AocError::Parse {
+                line,
+                column,
+                message,
+                ..
+            } => write!(f, "line {line}, column {column}: {message}"),
+            AocError::NoAnswer(message) => write!(f, "no answer: {message}"),
+            AocError::Unimplemented { year, day } => {
+                write!(f, "{year} day {day} is not implemented")
+            }
+            AocError::Request(message) => write!(f, "request failed: {message}"),
+            AocError::Usage(message) => write!(f, "{message}"),
+        }
+    }
+}
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<std::io::Error> for AocError {
+    fn from(e: std::io::Error) -> AocError {
+        AocError::Io(e)
+    }
+}
+
+/// Add up `values`, failing with [`AocError::overflow`] instead of
+/// overflowing.
+pub fn checked_sum(values: impl IntoIterator<Item = usize>) -> Result<usize> {
+    values.into_iter().try_fold(0usize, |sum, n| {
+        sum.checked_add(n)
+            .ok_or_else(|| AocError::overflow("the sum"))
+    })
+}
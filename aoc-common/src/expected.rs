@@ -0,0 +1,232 @@
+//! Expected answers for a day's inputs.
+//!
+//! Each day keeps the answers to its examples in an `expected.txt` next to its
+//! inputs, one input file per line followed by the answers to part 1 and part 2:
+//!
+//! ```text
+//! # file     part 1  part 2
+//! test.txt   142     281
+//! test2.txt  !       -
+//! ```
+//!
+//! An answer of `-` isn't checked, and `!` means the part is expected to fail.
+//!
+//! Known answers for real puzzle inputs go in an `answers.txt` in the same
+//! format, except that inputs are named by their [hash](crate::input::hash) so
+//! that answers for several people's inputs can be kept side by side.
+
+use crate::{Answers, AocError, Day, Part, Result, Solution, Streaming};
+use std::path::Path;
+
+pub const EXAMPLES_FILE: &str = "expected.txt";
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    Answer(String),
+    Failure,
+    Unchecked,
+}
+impl Expected {
+    fn parse(s: &str) -> Expected {
+        match s {
+            "-" => Expected::Unchecked,
+            "!" => Expected::Failure,
+            answer => Expected::Answer(answer.to_string()),
+        }
+    }
+    /// Compare a part's result against this expectation, describing any mismatch.
+    pub fn check(
+        &self,
+        result: Option<std::result::Result<&String, &AocError>>,
+    ) -> std::result::Result<(), String> {
+        match (self, result) {
+            (Expected::Unchecked, _) => Ok(()),
+            (Expected::Answer(expected), Some(Ok(answer))) if expected == answer => Ok(()),
+            (Expected::Failure, Some(Err(_))) => Ok(()),
+            (expected, Some(Ok(answer))) => Err(format!("expected {expected}, got {answer}")),
+            (expected, Some(Err(e))) => Err(format!("expected {expected}, got error: {e}")),
+            (expected, None) => Err(format!("expected {expected}, but the part wasn't run")),
+        }
+    }
+}
+impl std::fmt::Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Answer(answer) => write!(f, "{answer}"),
+            Expected::Failure => write!(f, "a failure"),
+            Expected::Unchecked => write!(f, "anything"),
+        }
+    }
+}
+
+/// The expected answers for one input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expectation {
+    /// The input's file name, or its hash for real inputs.
+    pub input: String,
+    pub part_one: Expected,
+    pub part_two: Expected,
+}
+impl Expectation {
+    pub fn get(&self, part: Part) -> &Expected {
+        match part {
+            Part::One => &self.part_one,
+            Part::Two => &self.part_two,
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Expectation>> {
+    let mut expectations = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [input, part_one, part_two] => expectations.push(Expectation {
+                input: input.to_string(),
+                part_one: Expected::parse(part_one),
+                part_two: Expected::parse(part_two),
+            }),
+            _ => {
+                return Err(AocError::parse(
+                    i + 1,
+                    1,
+                    "expected an input and two answers",
+                ))
+            }
+        }
+    }
+
+    Ok(expectations)
+}
+
+/// Load the expectations from a file like `expected.txt` or `answers.txt`.
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Expectation>> {
+    parse(&std::fs::read_to_string(path)?)
+}
+
+/// Find the known answers for a real input in a day's `answers.txt`.
+///
+/// Returns `None` if the input has no known answers, including if the day has
+/// no `answers.txt` at all.
+pub fn known_answers(dir: impl AsRef<Path>, input: &str) -> Result<Option<Expectation>> {
+    let path = dir.as_ref().join(ANSWERS_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let hash = crate::input::hash(input);
+    Ok(load(path)?.into_iter().find(|e| e.input == hash))
+}
+
+/// Check a run's answers against an expectation, returning every mismatch.
+pub fn check(expectation: &Expectation, answers: &Result<Answers>) -> Vec<String> {
+    let mut mismatches = vec![];
+
+    for part in [Part::One, Part::Two] {
+        let expected = expectation.get(part);
+        // If the input didn't parse, both parts failed with the parse error.
+        let result = match answers {
+            Ok(answers) => answers.get(part).map(|r| r.as_ref()),
+            Err(e) => Some(Err(e)),
+        };
+
+        if let Err(mismatch) = expected.check(result) {
+            mismatches.push(format!(
+                "{} part {}: {mismatch}",
+                expectation.input,
+                part.number()
+            ));
+        }
+    }
+
+    mismatches
+}
+
+/// Run a solution against every example listed in `dir`'s `expected.txt`,
+/// panicking with every mismatch if any answer was wrong.
+///
+/// This is meant to be called from each day's tests.
+pub fn assert_examples<S: Solution>(dir: impl AsRef<Path>) {
+    assert_answers(dir.as_ref(), |input| Day::of::<S>().run(input, None));
+}
+
+/// Like [`assert_examples`], but streaming each example through each part.
+pub fn assert_streamed_examples<S: Streaming>(dir: impl AsRef<Path>) {
+    let day = Day::streaming::<S>();
+    assert_answers(dir.as_ref(), |input| {
+        let stream = |part| day.stream(&mut input.as_bytes(), part);
+        Ok(Answers {
+            part_one: stream(Part::One),
+            part_two: stream(Part::Two),
+            ..Answers::default()
+        })
+    });
+}
+
+fn assert_answers(dir: &Path, run: impl Fn(&str) -> Result<Answers>) {
+    let expectations =
+        load(dir.join(EXAMPLES_FILE)).unwrap_or_else(|e| panic!("reading {EXAMPLES_FILE}: {e}"));
+    assert!(!expectations.is_empty(), "{EXAMPLES_FILE} has no examples");
+
+    let mut mismatches = vec![];
+    for expectation in &expectations {
+        let input = crate::input::read_file(&dir.join(&expectation.input))
+            .unwrap_or_else(|e| panic!("reading {}: {e}", expectation.input));
+        mismatches.extend(check(expectation, &run(&input)));
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_expectations() {
+        let expectations =
+            parse("# comment\ntest.txt 8 2286\n\ntest2.txt ! -  # trailing\n").unwrap();
+
+        assert_eq!(
+            expectations,
+            [
+                Expectation {
+                    input: "test.txt".into(),
+                    part_one: Expected::Answer("8".into()),
+                    part_two: Expected::Answer("2286".into()),
+                },
+                Expectation {
+                    input: "test2.txt".into(),
+                    part_one: Expected::Failure,
+                    part_two: Expected::Unchecked,
+                },
+            ]
+        );
+        assert!(parse("test.txt 8").is_err());
+    }
+
+    #[test]
+    fn checks_results() {
+        let error = AocError::NoAnswer("nothing".into());
+        let answer = "8".to_string();
+
+        assert!(Expected::Answer("8".into())
+            .check(Some(Ok(&answer)))
+            .is_ok());
+        assert!(Expected::Answer("9".into())
+            .check(Some(Ok(&answer)))
+            .is_err());
+        assert!(Expected::Answer("8".into())
+            .check(Some(Err(&error)))
+            .is_err());
+        assert!(Expected::Failure.check(Some(Err(&error))).is_ok());
+        assert!(Expected::Failure.check(Some(Ok(&answer))).is_err());
+        assert!(Expected::Unchecked.check(None).is_ok());
+    }
+}
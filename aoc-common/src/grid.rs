@@ -0,0 +1,353 @@
+//! A rectangular grid of cells, for puzzles laid out as a map or picture.
+
+use crate::{AocError, Result};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A cell's position in a [`Grid`]: `x` counts columns from the left and `y`
+/// counts rows from the top, both from 0.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+    /// The point `dx` columns right and `dy` rows down, or `None` if that's
+    /// off the top or left edge.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Point> {
+        Some(Point::new(
+            self.x.checked_add_signed(dx)?,
+            self.y.checked_add_signed(dy)?,
+        ))
+    }
+}
+
+/// The steps to the 4 neighbours that share an edge: up, right, down, left.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// The steps to all 8 neighbours, clockwise from the top left.
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A grid of `width` by `height` cells, stored row by row.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+impl<T> Grid<T> {
+    /// A grid with every cell set to `cell`.
+    pub fn new(width: usize, height: usize, cell: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+    /// A grid from its rows, or `None` if they aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+    /// Parse a grid with a row per line of `input`, turning each character
+    /// into a cell with `cell`.
+    ///
+    /// Errors from `cell` should be at column 1, like those from
+    /// [`AocError::at_column`], and are moved to where the character is.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Result<T>) -> Result<Grid<T>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let start = cells.len();
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).map_err(|e| e.offset_columns(i).at_line(y + 1))?);
+            }
+
+            let row = cells.len() - start;
+            match width {
+                None => width = Some(row),
+                Some(width) if row != width => {
+                    let column = match line.char_indices().nth(width) {
+                        Some((i, _)) => i + 1,
+                        None => line.len() + 1,
+                    };
+                    return Err(AocError::parse(
+                        y + 1,
+                        column,
+                        format!("expected a row of {width} cells, found {row}"),
+                    )
+                    .with_hint("every row of the grid must be as wide as the first"));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+    /// The cell at `point`, or `None` if it's outside the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+    /// The points that share an edge with `point` and are inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.steps(point, &ORTHOGONAL)
+    }
+    /// The points that share an edge or a corner with `point` and are inside
+    /// the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.steps(point, &SURROUNDING)
+    }
+    fn steps<'a>(
+        &'a self,
+        point: Point,
+        steps: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        steps
+            .iter()
+            .filter_map(move |&(dx, dy)| point.offset(dx, dy))
+            .filter(|&p| self.contains(p))
+    }
+
+    /// Each row, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+    /// Row `y`, which must be inside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+    /// Column `x` from the top, which must be inside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+    /// The points from `start` (included) in steps of `dx` and `dy`, until
+    /// they leave the grid.
+    pub fn ray(&self, start: Point, dx: isize, dy: isize) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(Some(start), move |p| p.offset(dx, dy))
+            .take_while(|&p| self.contains(p))
+    }
+    /// The cells on the diagonal from `start` down and to the right.
+    pub fn diagonal(&self, start: Point) -> impl Iterator<Item = &T> {
+        self.ray(start, 1, 1).map(|p| &self[p])
+    }
+    /// The cells on the diagonal from `start` down and to the left.
+    pub fn anti_diagonal(&self, start: Point) -> impl Iterator<Item = &T> {
+        self.ray(start, -1, 1).map(|p| &self[p])
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+    /// The grid flipped over its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |p| Point::new(p.y, p.x))
+    }
+    /// The grid turned a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |p| {
+            Point::new(p.y, self.height - 1 - p.x)
+        })
+    }
+    /// The grid turned a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |p| {
+            Point::new(self.width - 1 - p.y, p.x)
+        })
+    }
+    /// A `width` by `height` grid, taking each cell from the point in this
+    /// grid that `from` gives.
+    fn rearrange(&self, width: usize, height: usize, from: impl Fn(Point) -> Point) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let points = (0..height).flat_map(|y| (0..width).map(move |x| Point::new(x, y)));
+        Grid {
+            width,
+            height,
+            cells: points.map(|p| self[from(p)].clone()).collect(),
+        }
+    }
+}
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// The cell at `point`, panicking if it's outside the grid.
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+/// Written a row per line, with each cell's `Display` one after another.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+impl std::str::FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Grid<char>> {
+        Grid::parse(s, Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_indexes() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!("".parse::<Grid<char>>().unwrap().height(), 0);
+
+        let error = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a row of 3 cells, found 2"
+        );
+        let error = Grid::parse("12\n3x", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::at_column(1, format!("{c:?} isn't a digit")))
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: 'x' isn't a digit");
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        let corner: Vec<_> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+
+        let middle: String = grid
+            .neighbours8(Point::new(1, 0))
+            .map(|p| grid[p])
+            .collect();
+        assert_eq!(middle, "cfeda");
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 3);
+    }
+
+    #[test]
+    fn views() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.diagonal(Point::new(0, 0)).collect::<String>(), "ae");
+        assert_eq!(
+            grid.anti_diagonal(Point::new(2, 0)).collect::<String>(),
+            "ce"
+        );
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.iter().nth(4), Some((Point::new(1, 1), &'e')));
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+        let numbers = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(numbers.transpose().row(0), [1, 3]);
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    }
+}
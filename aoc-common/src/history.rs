@@ -0,0 +1,140 @@
+//! A local history of runs, for spotting performance regressions.
+//!
+//! Each recorded run is appended to `history.jsonl` at the root of the
+//! repository as one JSON object per line, holding the day's [`Report`] along
+//! with the git commit it was run at and the hash of its input.
+
+use crate::{input, paths, Answers, Day, Report, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::OpenOptions,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// One recorded run of a day.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// The commit the run was built from, as described by `git describe`, so
+    /// uncommitted changes show up as `-dirty`. `None` outside a git checkout.
+    pub commit: Option<String>,
+    /// The [hash](input::hash) of the input the day was run against.
+    pub input: String,
+    /// When the run happened, in seconds since the Unix epoch.
+    pub recorded_at: u64,
+    #[serde(flatten)]
+    pub report: Report,
+}
+impl Record {
+    /// A record of running `day` against `input`, at the current commit.
+    pub fn new(day: &Day, input: &str, answers: &Answers) -> Record {
+        let answer = |part: Option<&Result<String>>| part.and_then(|a| a.as_ref().ok()).cloned();
+
+        Record {
+            commit: commit(),
+            input: input::hash(input),
+            recorded_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |t| t.as_secs()),
+            report: Report {
+                year: day.year,
+                day: day.day,
+                part1: answer(answers.part_one.as_ref()),
+                part2: answer(answers.part_two.as_ref()),
+                timings: answers.timings,
+            },
+        }
+    }
+}
+
+/// Where the history is kept.
+pub fn path() -> PathBuf {
+    paths::root().join("history.jsonl")
+}
+
+/// Append a record to the history file at `path`, creating it if needed.
+pub fn append(path: &Path, record: &Record) -> Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(record).expect("records are always valid JSON");
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+/// Every record in the history file at `path`, oldest first. A missing file
+/// is an empty history.
+///
+/// Lines that can't be read as records are skipped, so that an interrupted
+/// write doesn't lose the rest of the history.
+pub fn load(path: &Path) -> Result<Vec<Record>> {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut records = vec![];
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        match serde_json::from_str(&line) {
+            Ok(record) => records.push(record),
+            Err(e) => crate::verbose!("{}:{}: skipping record: {e}", path.display(), i + 1),
+        }
+    }
+    Ok(records)
+}
+
+/// The current commit, or `None` if it can't be found.
+fn commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty", "--abbrev=12"])
+        .current_dir(paths::root())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timings;
+    use std::time::Duration;
+
+    #[test]
+    fn records_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        let record = Record {
+            commit: Some("0123456789ab".into()),
+            input: "0123456789abcdef".into(),
+            recorded_at: 1_700_000_000,
+            report: Report {
+                year: 2023,
+                day: 5,
+                part1: Some("35".into()),
+                part2: Some("46".into()),
+                timings: Timings {
+                    parse: Some(Duration::from_millis(1)),
+                    ..Timings::default()
+                },
+            },
+        };
+
+        assert_eq!(load(&path).unwrap(), vec![]);
+        append(&path, &record).unwrap();
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"truncated\n")
+            .unwrap();
+        append(&path, &record).unwrap();
+
+        let records = load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(records.unwrap(), vec![record.clone(), record]);
+    }
+}
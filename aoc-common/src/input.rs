@@ -0,0 +1,343 @@
+use crate::{paths, AocError, Result};
+use std::{
+    borrow::Cow,
+    io::{BufRead, Read},
+    path::PathBuf,
+};
+
+/// Where to read a day's input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Source {
+    /// The day's `input.txt`.
+    #[default]
+    Default,
+    /// One of the day's examples, numbered from 1.
+    Example(u8),
+    File(PathBuf),
+    Stdin,
+}
+impl Source {
+    /// The file this source reads from, or `None` for stdin.
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            Source::Default => Some(paths::input(year, day)),
+            Source::Example(n) => Some(paths::example(year, day, *n)),
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+        }
+    }
+    /// Read the whole input, [normalized](normalize).
+    pub fn read(&self, year: u16, day: u8) -> Result<String> {
+        Ok(normalize(&self.read_raw(year, day)?)?.into_owned())
+    }
+    /// Read the whole input as it is, for showing errors from normalizing it.
+    pub fn read_raw(&self, year: u16, day: u8) -> Result<String> {
+        match self.path(year, day) {
+            Some(path) => read_raw_file(&path),
+            None => read_raw_stdin(),
+        }
+    }
+    /// Open the input for reading a line at a time, rather than all at once.
+    pub fn open(&self, year: u16, day: u8) -> Result<Box<dyn BufRead>> {
+        match self.path(year, day) {
+            Some(path) => {
+                let file = std::fs::File::open(&path).map_err(|e| path_error(&path, e))?;
+                Ok(Box::new(std::io::BufReader::new(file)))
+            }
+            None => Ok(Box::new(std::io::stdin().lock())),
+        }
+    }
+}
+
+/// Read a whole input file, mentioning its path if that fails, and
+/// [normalize](normalize) it.
+pub fn read_file(path: &std::path::Path) -> Result<String> {
+    Ok(normalize(&read_raw_file(path)?)?.into_owned())
+}
+
+fn read_raw_file(path: &std::path::Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| path_error(path, e))
+}
+
+fn path_error(path: &std::path::Path, e: std::io::Error) -> AocError {
+    AocError::Io(std::io::Error::new(
+        e.kind(),
+        format!("{}: {e}", path.display()),
+    ))
+}
+
+/// Read all of stdin into a string, and [normalize](normalize) it.
+pub fn read_stdin() -> Result<String> {
+    Ok(normalize(&read_raw_stdin()?)?.into_owned())
+}
+
+fn read_raw_stdin() -> Result<String> {
+    let mut buffer = String::new();
+    std::io::stdin().read_to_string(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Make an input look like one downloaded from the site, whatever an editor
+/// or a copy and paste did to it: no byte order mark, `\n` line endings, and a
+/// single newline at the end of the last line, with no blank lines after it.
+///
+/// Puzzle inputs are plain ASCII, which parsers rely on to treat bytes as
+/// characters, so anything else is an error. So is a `\r` that isn't part of
+/// a `\r\n`, which would otherwise end up in the middle of a line.
+pub fn normalize(input: &str) -> Result<Cow<'_, str>> {
+    let input = without_byte_order_mark(input);
+    if let Some(e) = non_ascii(input).or_else(|| lone_carriage_return(input)) {
+        return Err(e);
+    }
+
+    let input = if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    };
+    // Keep the last line that isn't blank, and drop the blank lines after it.
+    let content = input.trim_end_matches(|c: char| c.is_ascii_whitespace());
+    let end = match (content.len(), input[content.len()..].find('\n')) {
+        (0, _) => 0,
+        (len, Some(newline)) => len + newline + 1,
+        (_, None) => input.len(),
+    };
+    let mut input = match input {
+        Cow::Borrowed(input) => Cow::Borrowed(&input[..end]),
+        Cow::Owned(mut input) => {
+            input.truncate(end);
+            Cow::Owned(input)
+        }
+    };
+    if !input.is_empty() && !input.ends_with('\n') {
+        input.to_mut().push('\n');
+    }
+    Ok(input)
+}
+
+/// `text` without the byte order mark some editors put at the start, which is
+/// what errors from [`normalize`] are located in.
+pub fn without_byte_order_mark(text: &str) -> &str {
+    text.strip_prefix('\u{feff}').unwrap_or(text)
+}
+
+/// An error pointing at the first non-ASCII character in `text`, if any.
+fn non_ascii(text: &str) -> Option<AocError> {
+    let offset = text.find(|c: char| !c.is_ascii())?;
+    let c = text[offset..].chars().next()?;
+    let (line, column) = location(text, &text[offset..]);
+    Some(
+        AocError::parse(
+            line,
+            column,
+            format!("unexpected non-ASCII character {c:?}"),
+        )
+        .with_hint("puzzle inputs are plain ASCII"),
+    )
+}
+
+/// An error pointing at the first `\r` in `text` that isn't followed by a
+/// `\n`, if any.
+fn lone_carriage_return(text: &str) -> Option<AocError> {
+    let (offset, _) = text
+        .match_indices('\r')
+        .find(|&(i, _)| !text[i + 1..].starts_with('\n'))?;
+    let (line, column) = location(text, &text[offset..]);
+    Some(
+        AocError::parse(line, column, "unexpected carriage return")
+            .with_hint("lines end with \"\\n\" or \"\\r\\n\""),
+    )
+}
+
+/// Parse every line of the input with `parse`, which receives the zero-based
+/// line index and the line without its line ending.
+///
+/// Errors are reported with the one-based line number they occurred on.
+pub fn parse_lines<T, F>(input: &str, mut parse: F) -> Result<Vec<T>>
+where
+    F: FnMut(usize, &str) -> Result<T>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(i, line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Fold `f` over every line read from `reader`, like [`parse_lines`] but
+/// without holding more than one line in memory, so that inputs of any size
+/// can be streamed. `f` receives the zero-based line index and the line
+/// without its line ending, [normalized](normalize) as it would be if the
+/// input were read all at once. Like there, blank lines at the end of the
+/// input are skipped, so blank lines are only passed on once a line that
+/// isn't blank follows them.
+///
+/// Errors are reported with the one-based line number they occurred on.
+pub fn fold_lines<T, F>(mut reader: impl BufRead, init: T, mut f: F) -> Result<T>
+where
+    F: FnMut(T, usize, &str) -> Result<T>,
+{
+    let mut buffer = String::new();
+    let mut blank: Vec<(usize, String)> = vec![];
+    let mut acc = init;
+
+    for i in 0.. {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+        let line = match buffer.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => &buffer,
+        };
+        let line = match i {
+            0 => without_byte_order_mark(line),
+            _ => line,
+        };
+        if let Some(e) = non_ascii(line).or_else(|| lone_carriage_return(line)) {
+            return Err(e.at_line(i + 1));
+        }
+        if line.trim_ascii().is_empty() {
+            blank.push((i, line.to_string()));
+            continue;
+        }
+        for (i, line) in blank.drain(..) {
+            acc = f(acc, i, &line).map_err(|e| e.at_line(i + 1))?;
+        }
+        acc = f(acc, i, line).map_err(|e| e.at_line(i + 1))?;
+    }
+
+    Ok(acc)
+}
+
+/// The byte offset of `inner` within `outer`, which it must be a slice of.
+pub fn offset(outer: &str, inner: &str) -> usize {
+    let offset = (inner.as_ptr() as usize).wrapping_sub(outer.as_ptr() as usize);
+    assert!(
+        offset <= outer.len(),
+        "offset() needs a slice of the outer string"
+    );
+    offset
+}
+
+/// Find the one-based line and column that `rest`, a suffix of `input`, starts
+/// at.
+///
+/// This is useful for turning the remaining input of a failed parser back into
+/// a location in the original input.
+pub fn location(input: &str, rest: &str) -> (usize, usize) {
+    let offset = input.len().saturating_sub(rest.len());
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (before.matches('\n').count() + 1, offset - line_start + 1)
+}
+
+/// A stable hash of the input, used to tell different people's inputs apart.
+///
+/// This is 64-bit FNV-1a, formatted as hex. It isn't cryptographic, but unlike
+/// `std`'s hashers it's guaranteed to be the same everywhere.
+pub fn hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_is_fnv1a() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(hash("foobar"), "85944171f73967e8");
+    }
+
+    #[test]
+    fn locations() {
+        let input = "one\ntwo\nthree";
+        assert_eq!(location(input, input), (1, 1));
+        assert_eq!(location(input, &input[4..]), (2, 1));
+        assert_eq!(location(input, "ee"), (3, 4));
+        assert_eq!(location(input, ""), (3, 6));
+    }
+
+    #[test]
+    fn parse_lines_sets_line_numbers() {
+        let input = "1\n2\nx\n4";
+        let e = parse_lines(input, |_, line| {
+            line.parse::<usize>()
+                .map_err(|_| AocError::at_column(1, "not a number"))
+        })
+        .unwrap_err();
+
+        assert_eq!(e.to_string(), "line 3, column 1: not a number");
+        assert_eq!(offset(input, &input[4..]), 4);
+    }
+
+    #[test]
+    fn fold_lines_like_parse_lines() {
+        let sum = |input: &str| {
+            fold_lines(input.as_bytes(), 0, |sum, _, line| {
+                line.parse::<usize>()
+                    .map(|n| sum + n)
+                    .map_err(|_| AocError::at_column(1, format!("not a number: {line:?}")))
+            })
+        };
+
+        assert_eq!(sum("\u{feff}1\r\n2\n3").unwrap(), 6);
+        assert_eq!(sum("").unwrap(), 0);
+        assert_eq!(
+            sum("1\n2\n\n4\n").unwrap_err().to_string(),
+            "line 3, column 1: not a number: \"\""
+        );
+        assert_eq!(
+            sum("1\n2\u{a0}\n").unwrap_err().to_string(),
+            "line 2, column 2: unexpected non-ASCII character '\\u{a0}'"
+        );
+        assert_eq!(
+            sum("1\r\n2\r3\n").unwrap_err().to_string(),
+            "line 2, column 2: unexpected carriage return"
+        );
+        assert!(sum("1\n2\r").is_err());
+        assert_eq!(sum("1\n2\n\n").unwrap(), 3);
+        assert_eq!(sum("1\r\n2\r\n\r\n \r\n").unwrap(), 3);
+        assert_eq!(sum("\n\n").unwrap(), 0);
+    }
+
+    #[test]
+    fn normalizes_inputs() {
+        let normalize = |input| normalize(input).unwrap();
+
+        assert_eq!(normalize("1\n2\n"), "1\n2\n");
+        assert!(matches!(normalize("1\n2\n"), Cow::Borrowed(_)));
+        assert_eq!(normalize("\u{feff}1\r\n2\r\n"), "1\n2\n");
+        assert_eq!(normalize("1\n2"), "1\n2\n");
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize("1\n2\n\n"), "1\n2\n");
+        assert!(matches!(normalize("1\n2\n\n"), Cow::Borrowed(_)));
+        assert_eq!(normalize("1\r\n2\r\n\r\n"), "1\n2\n");
+        assert_eq!(normalize("1\n2 \n \n\t\n"), "1\n2 \n");
+        assert_eq!(normalize("1\n\n2\n  "), "1\n\n2\n");
+        assert_eq!(normalize("\n\n"), "");
+        assert_eq!(
+            super::normalize("\u{feff}ab\r\nc\u{2014}d")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 2: unexpected non-ASCII character '\u{2014}'"
+        );
+        assert_eq!(
+            super::normalize("1\r\n23\r4\r\n").unwrap_err().to_string(),
+            "line 2, column 3: unexpected carriage return"
+        );
+        assert!(super::normalize("1\r").is_err());
+
+        let raw = "\u{feff}red\nbl\u{e9}u\n";
+        let e = super::normalize(raw).unwrap_err();
+        let rendered = crate::diagnostic::render(&e, without_byte_order_mark(raw));
+        assert!(rendered.contains("2 | bl\u{e9}u\n  |   ^"), "{rendered}");
+    }
+}
@@ -0,0 +1,17 @@
+//! Shared helpers for the Advent of Code solutions in this repository.
+
+pub mod cli;
+pub mod diagnostic;
+pub mod error;
+pub mod expected;
+pub mod generate;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod paths;
+pub mod solution;
+
+pub use error::{checked_sum, AocError, Result};
+pub use solution::{Answers, Day, Part, Report, Solution, Streaming, Timings};
@@ -0,0 +1,116 @@
+//! A tiny logging layer, so that days can explain themselves without drowning
+//! out their answers.
+//!
+//! Everything is logged to stderr, keeping stdout for the results. The level is
+//! set once at startup from [`LogArgs`], and checked with the [`verbose!`] and
+//! [`trace!`] macros.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(clap::ValueEnum, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Only the answers and errors.
+    Quiet,
+    /// The answers with labels, plus anything noteworthy.
+    Normal,
+    /// Also what each phase of a run is doing.
+    Verbose,
+    /// Also every line of input and the parsed models.
+    Trace,
+}
+impl Level {
+    fn from_u8(level: u8) -> Level {
+        match level {
+            0 => Level::Quiet,
+            1 => Level::Normal,
+            2 => Level::Verbose,
+            _ => Level::Trace,
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Normal as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+/// Log a message at [`Level::Verbose`].
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Verbose) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Log a message at [`Level::Trace`].
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Command line options for choosing the log level.
+#[derive(clap::Args, Clone, Debug, Default)]
+pub struct LogArgs {
+    /// Log more; pass twice to echo every line of input.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    pub verbose: u8,
+    /// Only print the answers.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+    /// Set the log level by name.
+    #[arg(long, value_enum, env = "AOC_LOG", global = true)]
+    pub verbosity: Option<Level>,
+}
+impl LogArgs {
+    /// The level these options ask for. `-q` and `-v` take precedence over
+    /// `--verbosity` and `AOC_LOG`.
+    pub fn level(&self) -> Level {
+        if self.quiet {
+            Level::Quiet
+        } else if self.verbose > 0 {
+            Level::from_u8(Level::Normal as u8 + self.verbose)
+        } else {
+            self.verbosity.unwrap_or(Level::Normal)
+        }
+    }
+    pub fn init(&self) {
+        set_level(self.level());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_choose_the_level() {
+        let args = |verbose, quiet, verbosity| LogArgs {
+            verbose,
+            quiet,
+            verbosity,
+        };
+
+        assert_eq!(args(0, false, None).level(), Level::Normal);
+        assert_eq!(args(0, true, None).level(), Level::Quiet);
+        assert_eq!(args(1, false, None).level(), Level::Verbose);
+        assert_eq!(args(2, false, None).level(), Level::Trace);
+        assert_eq!(args(5, false, None).level(), Level::Trace);
+        assert_eq!(args(0, false, Some(Level::Trace)).level(), Level::Trace);
+        assert_eq!(args(1, false, Some(Level::Quiet)).level(), Level::Verbose);
+    }
+}
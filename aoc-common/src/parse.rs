@@ -0,0 +1,343 @@
+//! nom parsers for the pieces puzzle inputs are commonly made of, with an
+//! error type that turns into an [`AocError`] pointing at where parsing
+//! failed.
+//!
+//! Errors are explained with [`context`](nom::error::context): the innermost
+//! context becomes the error's message, and the next one out becomes its
+//! hint.
+
+use crate::{input, AocError};
+use nom::{
+    character::complete::{char, digit1, line_ending, one_of, space0, space1},
+    combinator::{opt, recognize, value},
+    error::{ContextError, ErrorKind, FromExternalError, ParseError},
+    multi::{many1, separated_list0},
+    sequence::{pair, separated_pair, tuple},
+    Parser,
+};
+use std::{fmt, str::FromStr};
+
+/// The result of a parser using this module's [`Error`].
+pub type IResult<'a, T> = nom::IResult<&'a str, T, Error<'a>>;
+
+/// Why a parser failed, and where.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error<'a> {
+    /// The input from where parsing failed.
+    pub rest: &'a str,
+    pub message: String,
+    pub hint: Option<String>,
+    /// Whether the message says what was expected, rather than just naming
+    /// the nom parser that failed. A context replaces a message that doesn't,
+    /// and otherwise becomes the hint.
+    explained: bool,
+}
+impl<'a> Error<'a> {
+    /// An error at `rest`, explained by `message`.
+    pub fn new(rest: &'a str, message: impl fmt::Display) -> Error<'a> {
+        Error {
+            rest,
+            message: message.to_string(),
+            hint: None,
+            explained: true,
+        }
+    }
+    pub fn with_hint(mut self, hint: impl fmt::Display) -> Error<'a> {
+        self.hint = Some(hint.to_string());
+        self
+    }
+    /// Turn this into an [`AocError`] located within `input`, which the
+    /// error's `rest` must be a suffix of.
+    pub fn into_aoc_error(self, input: &str) -> AocError {
+        let (line, column) = input::location(input, self.rest);
+        let error = AocError::parse(line, column, self.message);
+        match self.hint {
+            Some(hint) => error.with_hint(hint),
+            None => error,
+        }
+    }
+}
+impl<'a> ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Error<'a> {
+        Error {
+            rest: input,
+            message: kind.description().to_lowercase(),
+            hint: None,
+            explained: false,
+        }
+    }
+    fn append(_: &'a str, _: ErrorKind, other: Error<'a>) -> Error<'a> {
+        other
+    }
+    fn from_char(input: &'a str, c: char) -> Error<'a> {
+        Error {
+            message: format!("expected {c:?}"),
+            ..Error::from_error_kind(input, ErrorKind::Char)
+        }
+    }
+}
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(_: &'a str, context: &'static str, mut other: Error<'a>) -> Error<'a> {
+        if !other.explained {
+            other.message = context.to_string();
+            other.explained = true;
+        } else if other.hint.is_none() {
+            other.hint = Some(context.to_string());
+        }
+        other
+    }
+}
+impl<'a, E> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Error<'a> {
+        Error::from_error_kind(input, kind)
+    }
+}
+
+/// Parse all of `input` with `parser`, turning a failure into an [`AocError`]
+/// pointing at where it happened.
+pub fn finish<'a, T>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, T, Error<'a>>,
+) -> crate::Result<T> {
+    let error = match parser.parse(input) {
+        Ok(("", parsed)) => return Ok(parsed),
+        Ok((rest, _)) => quoted(rest, "unexpected"),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e,
+        Err(nom::Err::Incomplete(_)) => {
+            Error::new(&input[input.len()..], "unexpected end of input")
+        }
+    };
+    Err(error.into_aoc_error(input))
+}
+
+/// Replace the message of any error from `parser`, for when its own message
+/// is too specific.
+pub fn expect<'a, T>(
+    message: &'static str,
+    mut parser: impl Parser<&'a str, T, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    move |input| {
+        parser
+            .parse(input)
+            .map_err(|e| e.map(|e| Error::new(e.rest, message)))
+    }
+}
+
+/// Like [`expect`], but quoting the text where `parser` failed, as in
+/// `unknown color "purple"`.
+pub fn quoting<'a, T>(
+    message: &'static str,
+    mut parser: impl Parser<&'a str, T, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    move |input| {
+        parser
+            .parse(input)
+            .map_err(|e| e.map(|e| quoted(e.rest, message)))
+    }
+}
+
+/// An error at `rest` quoting the word it starts with.
+fn quoted(rest: &str, message: impl fmt::Display) -> Error<'_> {
+    // A word ends at whitespace or at the punctuation that separates items.
+    let end = match rest.find(|c: char| c.is_whitespace() || ":,;|".contains(c)) {
+        Some(0) => rest.chars().next().map_or(0, char::len_utf8),
+        Some(end) => end,
+        None => rest.len(),
+    };
+    Error::new(rest, format!("{message} {:?}", &rest[..end]))
+}
+
+/// An unsigned decimal number, of any integer type.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<'_, T> {
+    let (rest, digits) = expect("expected a number", digit1)(input)?;
+    number(input, rest, digits)
+}
+
+/// A decimal number with an optional sign, of any integer type.
+pub fn signed<T: FromStr>(input: &str) -> IResult<'_, T> {
+    let (rest, digits) = expect(
+        "expected a number",
+        recognize(pair(opt(one_of("+-")), digit1)),
+    )(input)?;
+    number(input, rest, digits)
+}
+
+fn number<'a, T: FromStr>(input: &'a str, rest: &'a str, digits: &str) -> IResult<'a, T> {
+    match digits.parse() {
+        Ok(n) => Ok((rest, n)),
+        // The digits are fine, so nothing else could parse them either.
+        Err(_) => Err(nom::Err::Failure(Error::new(
+            input,
+            "number is out of range",
+        ))),
+    }
+}
+
+/// Any number of items on one line, separated by spaces or tabs.
+pub fn list<'a, T>(
+    item: impl Parser<&'a str, T, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list0(space1, item)
+}
+
+/// A header like `Card 12:`, giving its number.
+pub fn header<'a, T: FromStr>(label: &'static str) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    move |input| {
+        let noun = label.to_lowercase();
+        let expected_label = |e: nom::Err<Error<'a>>| {
+            e.map(|e| Error::new(e.rest, format!("expected \"{label} \"")))
+        };
+
+        let (rest, _) = nom::bytes::complete::tag(label)(input).map_err(expected_label)?;
+        let (rest, _) = space1(rest).map_err(expected_label)?;
+        let (rest, n) = unsigned(rest)
+            .map_err(|e| e.map(|e| quoted(e.rest, format_args!("invalid {noun} ID"))))?;
+        let colon: IResult<'a, _> = pair(space0, char(':'))(rest);
+        let (rest, _) = colon.map_err(|e| {
+            e.map(|e| Error::new(e.rest, format!("expected a \":\" after the {noun} ID")))
+        })?;
+
+        Ok((rest, n))
+    }
+}
+
+/// A `key: value` pair, with any spaces around the colon.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<&'a str, K, Error<'a>>,
+    value: impl Parser<&'a str, V, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, (K, V)> {
+    separated_pair(key, tuple((space0, char(':'), space0)), value)
+}
+
+/// The end of a line followed by at least one empty line.
+pub fn blank_line(input: &str) -> IResult<'_, ()> {
+    value((), pair(line_ending, many1(pair(space0, line_ending))))(input)
+}
+
+/// One or more items on consecutive lines, ending at a blank line or the end
+/// of the input. Any other line must be another item.
+pub fn lines<'a, T>(
+    mut item: impl Parser<&'a str, T, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    move |input| {
+        let (mut rest, first) = item.parse(input)?;
+        let mut items = vec![first];
+
+        while let Ok((next, _)) = line_ending::<_, Error>(rest) {
+            if next
+                .lines()
+                .next()
+                .is_none_or(|line| line.trim().is_empty())
+            {
+                break;
+            }
+            let (next, parsed) = item.parse(next)?;
+            items.push(parsed);
+            rest = next;
+        }
+
+        Ok((rest, items))
+    }
+}
+
+/// One or more sections separated by blank lines. Once a blank line is
+/// followed by anything but whitespace, that must be another section.
+pub fn sections<'a, T>(
+    mut section: impl Parser<&'a str, T, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    move |input| {
+        let (mut rest, first) = section.parse(input)?;
+        let mut sections = vec![first];
+
+        while let Ok((next, ())) = blank_line(rest) {
+            if next.trim().is_empty() {
+                break;
+            }
+            let (next, parsed) = section.parse(next)?;
+            sections.push(parsed);
+            rest = next;
+        }
+
+        Ok((rest, sections))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{bytes::complete::tag, error::context};
+
+    fn error<'a, T>(input: &'a str, parser: impl FnMut(&'a str) -> IResult<'a, T>) -> String {
+        match finish(input, parser) {
+            Ok(_) => panic!("expected {input:?} not to parse"),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(unsigned::<u8>("255 red"), Ok((" red", 255)));
+        assert_eq!(signed::<i64>("-12"), Ok(("", -12)));
+        assert_eq!(signed::<i8>("+7"), Ok(("", 7)));
+        assert_eq!(
+            error("x", unsigned::<u8>),
+            "line 1, column 1: expected a number"
+        );
+        assert_eq!(
+            error("256", unsigned::<u8>),
+            "line 1, column 1: number is out of range"
+        );
+        assert_eq!(
+            error("-1", unsigned::<u32>),
+            "line 1, column 1: expected a number"
+        );
+    }
+
+    #[test]
+    fn lists_headers_and_pairs() {
+        assert_eq!(
+            list(unsigned::<u32>)("1  2\t3 |"),
+            Ok((" |", vec![1, 2, 3]))
+        );
+        assert_eq!(list(unsigned::<u32>)(""), Ok(("", vec![])));
+        assert_eq!(header::<u32>("Card")("Card   12: 1"), Ok((" 1", 12)));
+        assert_eq!(
+            error("Card x: 1", header::<u32>("Card")),
+            "line 1, column 6: invalid card ID \"x\""
+        );
+        assert_eq!(
+            error("Crd 1:", header::<u32>("Card")),
+            "line 1, column 1: expected \"Card \""
+        );
+        assert_eq!(
+            key_value(tag("seeds"), list(unsigned::<u64>))("seeds: 79 14"),
+            Ok(("", ("seeds", vec![79, 14])))
+        );
+    }
+
+    #[test]
+    fn lines_sections_and_errors() {
+        let numbers = |input| lines(unsigned::<u32>)(input);
+        assert_eq!(
+            sections(numbers)("1\n2\n\n3\n \n\n4\n"),
+            Ok(("\n", vec![vec![1, 2], vec![3], vec![4]]))
+        );
+
+        let mut parser = context(
+            "sections are numbers",
+            sections(context("expected digits", numbers)),
+        );
+        let error = finish("1\n\nx", &mut parser).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected a number");
+        let AocError::Parse { hint, .. } = error else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(hint.as_deref(), Some("expected digits"));
+
+        let error = finish("1\n\n2 ;", &mut parser).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 2: unexpected \" \"");
+
+        let error = finish("1\n2\nx\n", &mut parser).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected a number");
+    }
+}
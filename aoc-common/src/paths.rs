@@ -0,0 +1,39 @@
+use std::path::{Path, PathBuf};
+
+/// The root of the repository, which can be overridden with `AOC_ROOT`.
+pub fn root() -> PathBuf {
+    match std::env::var_os("AOC_ROOT") {
+        Some(root) => PathBuf::from(root),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("aoc-common is inside the repository")
+            .to_path_buf(),
+    }
+}
+
+/// Where downloaded files are kept, which can be overridden with `AOC_CACHE`.
+pub fn cache() -> PathBuf {
+    match std::env::var_os("AOC_CACHE") {
+        Some(cache) => PathBuf::from(cache),
+        None => root().join(".cache"),
+    }
+}
+
+/// The directory holding a day's crate and its inputs.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    root().join(year.to_string()).join(format!("day-{day}"))
+}
+
+/// A day's real puzzle input.
+pub fn input(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("input.txt")
+}
+
+/// One of a day's example inputs: `test.txt` for the first, then `test2.txt`,
+/// `test3.txt` and so on.
+pub fn example(year: u16, day: u8, n: u8) -> PathBuf {
+    match n {
+        0 | 1 => day_dir(year, day).join("test.txt"),
+        n => day_dir(year, day).join(format!("test{n}.txt")),
+    }
+}
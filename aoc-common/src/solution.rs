@@ -0,0 +1,320 @@
+use crate::{AocError, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    io::BufRead,
+    time::{Duration, Instant},
+};
+
+/// A solution to one day's puzzle.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<Self::Answer>;
+    fn part_two(input: &Self::Input) -> Result<Self::Answer>;
+}
+
+/// A solution that can also solve each part in a single pass over the
+/// input's lines, for inputs too big to read into memory.
+pub trait Streaming: Solution {
+    fn stream_part_one(input: &mut dyn BufRead) -> Result<Self::Answer>;
+    fn stream_part_two(input: &mut dyn BufRead) -> Result<Self::Answer>;
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+impl std::str::FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Part, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("expected 1 or 2, found {s:?}")),
+        }
+    }
+}
+
+/// The answers from running a solution. A part is `None` if it wasn't run.
+///
+/// Each part succeeds or fails on its own, since some inputs (like an example
+/// written for part 2) only work for one of them.
+#[derive(Debug, Default)]
+pub struct Answers {
+    pub part_one: Option<Result<String>>,
+    pub part_two: Option<Result<String>>,
+    pub timings: Timings,
+}
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&Result<String>> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+}
+
+/// Solves one part of a [`Streaming`] solution.
+type StreamFn = fn(&mut dyn BufRead, Part) -> Result<String>;
+
+/// A type-erased [`Solution`], so that days can be looked up at runtime.
+#[derive(Copy, Clone)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    run: fn(&str, Option<Part>) -> Result<Answers>,
+    stream: Option<StreamFn>,
+}
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            run: run::<S>,
+            stream: None,
+        }
+    }
+    /// Like [`Day::of`], for a solution that can also stream its input.
+    pub const fn streaming<S: Streaming>() -> Day {
+        Day {
+            stream: Some(stream::<S>),
+            ..Day::of::<S>()
+        }
+    }
+    /// Run the given part against the input, or both parts if `part` is `None`.
+    ///
+    /// The input should already be [normalized](crate::input::normalize), as
+    /// it is when read through [`input`](crate::input). This only fails if the
+    /// input couldn't be parsed.
+    pub fn run(&self, input: &str, part: Option<Part>) -> Result<Answers> {
+        (self.run)(input, part)
+    }
+    /// Solve one part in a single pass over the input, or `None` if this day
+    /// can't stream its input.
+    pub fn stream(&self, input: &mut dyn BufRead, part: Part) -> Option<Result<String>> {
+        self.stream.map(|stream| stream(input, part))
+    }
+}
+impl std::fmt::Debug for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Day")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .finish()
+    }
+}
+
+fn run<S: Solution>(input: &str, part: Option<Part>) -> Result<Answers> {
+    let mut answers = Answers::default();
+
+    crate::verbose!("{} day {}: parsing {} bytes", S::YEAR, S::DAY, input.len());
+    let input = time(&mut answers.timings.parse, || S::parse(input))?;
+    crate::verbose!("parsed in {:?}", answers.timings.parse.unwrap_or_default());
+
+    if part != Some(Part::Two) {
+        let answer = time(&mut answers.timings.part1, || S::part_one(&input));
+        crate::verbose!(
+            "part 1 took {:?}",
+            answers.timings.part1.unwrap_or_default()
+        );
+        answers.part_one = Some(answer.map(|a| a.to_string()));
+    }
+    if part != Some(Part::One) {
+        let answer = time(&mut answers.timings.part2, || S::part_two(&input));
+        crate::verbose!(
+            "part 2 took {:?}",
+            answers.timings.part2.unwrap_or_default()
+        );
+        answers.part_two = Some(answer.map(|a| a.to_string()));
+    }
+
+    Ok(answers)
+}
+
+fn stream<S: Streaming>(input: &mut dyn BufRead, part: Part) -> Result<String> {
+    let answer = match part {
+        Part::One => S::stream_part_one(input)?,
+        Part::Two => S::stream_part_two(input)?,
+    };
+    Ok(answer.to_string())
+}
+
+fn time<T>(timing: &mut Option<Duration>, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    *timing = Some(start.elapsed());
+    result
+}
+
+/// How long each phase of a run took. A phase is `None` if it didn't run.
+///
+/// Timings are serialized as fractional milliseconds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Timings {
+    #[serde(with = "millis")]
+    pub read: Option<Duration>,
+    #[serde(with = "millis")]
+    pub parse: Option<Duration>,
+    #[serde(with = "millis")]
+    pub part1: Option<Duration>,
+    #[serde(with = "millis")]
+    pub part2: Option<Duration>,
+}
+impl Timings {
+    /// The time taken by every phase that ran.
+    pub fn total(&self) -> Duration {
+        [self.read, self.parse, self.part1, self.part2]
+            .into_iter()
+            .flatten()
+            .sum()
+    }
+}
+impl std::ops::Add for Timings {
+    type Output = Timings;
+
+    /// Add each phase, treating phases that didn't run as taking no time
+    /// unless neither side ran them.
+    fn add(self, other: Timings) -> Timings {
+        let add = |a: Option<Duration>, b: Option<Duration>| match (a, b) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+        Timings {
+            read: add(self.read, other.read),
+            parse: add(self.parse, other.parse),
+            part1: add(self.part1, other.part1),
+            part2: add(self.part2, other.part2),
+        }
+    }
+}
+impl std::iter::Sum for Timings {
+    fn sum<I: Iterator<Item = Timings>>(iter: I) -> Timings {
+        iter.fold(Timings::default(), |a, b| a + b)
+    }
+}
+
+/// The outcome of running a day, in the form it's reported to other tools.
+///
+/// A part's answer is `None` if it wasn't run or if it failed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub timings: Timings,
+}
+impl Report {
+    /// Build a report from a run's answers, also returning the errors from any
+    /// parts that failed.
+    pub fn new(day: &Day, answers: Answers) -> (Report, Vec<(Part, AocError)>) {
+        let mut errors = vec![];
+        let mut answer = |part, result: Option<Result<String>>| match result {
+            Some(Ok(answer)) => Some(answer),
+            Some(Err(e)) => {
+                errors.push((part, e));
+                None
+            }
+            None => None,
+        };
+
+        let report = Report {
+            year: day.year,
+            day: day.day,
+            part1: answer(Part::One, answers.part_one),
+            part2: answer(Part::Two, answers.part_two),
+            timings: answers.timings,
+        };
+        (report, errors)
+    }
+}
+
+mod millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+        match d {
+            Some(d) => s.serialize_some(&(d.as_secs_f64() * 1000.0)),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Duration>, D::Error> {
+        let millis: Option<f64> = Option::deserialize(d)?;
+        Ok(millis.map(|ms| Duration::from_secs_f64(ms / 1000.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_json() {
+        let report = Report {
+            year: 2023,
+            day: 1,
+            part1: Some("142".into()),
+            part2: None,
+            timings: Timings {
+                read: Some(Duration::from_micros(250)),
+                parse: Some(Duration::from_micros(1500)),
+                part1: Some(Duration::from_millis(2)),
+                part2: None,
+            },
+        };
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(
+            json,
+            r#"{"year":2023,"day":1,"part1":"142","part2":null,"timings":{"read":0.25,"parse":1.5,"part1":2.0,"part2":null}}"#
+        );
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    }
+
+    #[test]
+    fn timings_add_up() {
+        let ms = |n| Some(Duration::from_millis(n));
+        let a = Timings {
+            read: ms(1),
+            parse: ms(2),
+            part1: ms(3),
+            part2: None,
+        };
+        let b = Timings {
+            read: ms(10),
+            parse: ms(20),
+            part1: None,
+            part2: None,
+        };
+
+        assert_eq!(a.total(), Duration::from_millis(6));
+        assert_eq!(
+            [a, b].into_iter().sum::<Timings>(),
+            Timings {
+                read: ms(11),
+                parse: ms(22),
+                part1: ms(3),
+                part2: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use aoc_common::{AocError, Part, Result};
+
+/// Options for talking to the puzzle server.
+#[derive(clap::Args, Clone, Debug)]
+pub struct ClientArgs {
+    /// The puzzle server to talk to.
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    pub base_url: String,
+    /// The value of your `session` cookie from the puzzle website.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+}
+
+/// A client for the puzzle server, logged in with a session cookie.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+impl Client {
+    pub fn new(args: &ClientArgs) -> Client {
+        Client {
+            base_url: args.base_url.trim_end_matches('/').to_string(),
+            session: args.session.clone(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!(
+                    "github.com/garentyler/advent-of-code aoc/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build(),
+        }
+    }
+    /// Download a day's puzzle input.
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        aoc_common::verbose!("downloading {url}");
+
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .call()
+            .map_err(|e| request_error(&url, e))?
+            .into_string()
+            .map_err(|e| AocError::Request(format!("{url}: {e}")))
+    }
+    /// Submit an answer, returning the page the server responds with.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        aoc_common::verbose!("submitting {answer} to {url}");
+
+        self.agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .send_form(&[("level", &part.number().to_string()), ("answer", answer)])
+            .map_err(|e| request_error(&url, e))?
+            .into_string()
+            .map_err(|e| AocError::Request(format!("{url}: {e}")))
+    }
+    fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            AocError::Request("no session token; set AOC_SESSION to your session cookie".into())
+        })
+    }
+}
+
+fn request_error(url: &str, e: ureq::Error) -> AocError {
+    match e {
+        ureq::Error::Status(400, _) => {
+            AocError::Request(format!("{url}: the session token was rejected"))
+        }
+        ureq::Error::Status(404, _) => {
+            AocError::Request(format!("{url}: the puzzle isn't available yet"))
+        }
+        e => AocError::Request(e.to_string()),
+    }
+}
@@ -0,0 +1,124 @@
+use crate::client::Client;
+use aoc_common::{paths, Result};
+use std::path::{Path, PathBuf};
+
+/// Where a day's downloaded input is cached.
+pub fn cache_path(cache: &Path, year: u16, day: u8) -> PathBuf {
+    cache
+        .join("inputs")
+        .join(year.to_string())
+        .join(format!("day-{day}.txt"))
+}
+
+/// Get a day's input, downloading it only if it isn't already in the cache.
+/// Returns the cached file and whether it was downloaded.
+pub fn fetch(client: &Client, cache: &Path, year: u16, day: u8) -> Result<(PathBuf, bool)> {
+    let path = cache_path(cache, year, day);
+    if path.exists() {
+        aoc_common::verbose!("using cached {}", path.display());
+        return Ok((path, false));
+    }
+
+    let input = client.input(year, day)?;
+    std::fs::create_dir_all(path.parent().expect("cache paths have a parent"))?;
+    // Write to a temporary file first so an interrupted write can't leave a
+    // partial input in the cache.
+    let partial = path.with_extension("part");
+    std::fs::write(&partial, input)?;
+    std::fs::rename(&partial, &path)?;
+
+    Ok((path, true))
+}
+
+/// Copy a cached input to the day's `input.txt`, if the day exists and doesn't
+/// have an input yet. Returns where it was copied to.
+pub fn install(cached: &Path, year: u16, day: u8) -> Result<Option<PathBuf>> {
+    let input = paths::input(year, day);
+    if !paths::day_dir(year, day).is_dir() || input.exists() {
+        return Ok(None);
+    }
+
+    std::fs::copy(cached, &input)?;
+    Ok(Some(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::ClientArgs, mock};
+
+    fn client(base_url: &str) -> Client {
+        Client::new(&ClientArgs {
+            base_url: base_url.into(),
+            session: Some("abc123".into()),
+        })
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once() {
+        let (base_url, server) = mock::serve(1, |_| (200, "1abc2\npqr3stu8vwx\n"));
+        let client = client(&base_url);
+        let cache = cache_dir("fetch");
+
+        let (path, downloaded) = fetch(&client, &cache, 2023, 6).unwrap();
+        assert!(downloaded);
+        assert_eq!(path, cache_path(&cache, 2023, 6));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "1abc2\npqr3stu8vwx\n"
+        );
+
+        // The server only answers one request, so this must come from the cache.
+        assert_eq!(fetch(&client, &cache, 2023, 6).unwrap(), (path, false));
+
+        std::fs::remove_dir_all(&cache).unwrap();
+        let [request] = &server.join().unwrap()[..] else {
+            panic!("expected one request");
+        };
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.url, "/2023/day/6/input");
+        assert_eq!(request.cookie.as_deref(), Some("session=abc123"));
+    }
+
+    #[test]
+    fn reports_failures() {
+        let (base_url, server) = mock::serve(2, |request| match request.url.as_str() {
+            "/2023/day/6/input" => (400, "Puzzle inputs differ by user."),
+            _ => (404, "Not Found"),
+        });
+        let client = client(&base_url);
+        let cache = cache_dir("fetch-failures");
+
+        let error = fetch(&client, &cache, 2023, 6).unwrap_err().to_string();
+        assert!(error.ends_with("the session token was rejected"), "{error}");
+        let error = fetch(&client, &cache, 2023, 25).unwrap_err().to_string();
+        assert!(error.ends_with("the puzzle isn't available yet"), "{error}");
+
+        assert!(!cache.exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn only_needs_a_session_to_download() {
+        let client = Client::new(&ClientArgs {
+            base_url: "http://127.0.0.1:1".into(),
+            session: None,
+        });
+        let cache = cache_dir("fetch-session");
+
+        let error = fetch(&client, &cache, 2023, 6).unwrap_err().to_string();
+        assert!(error.contains("no session token"), "{error}");
+
+        let path = cache_path(&cache, 2023, 6);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "cached").unwrap();
+        assert_eq!(fetch(&client, &cache, 2023, 6).unwrap(), (path, false));
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_common::{AocError, Result};
+
+/// How big a generated input should be. What each option controls depends on
+/// the day.
+#[derive(clap::Args, Clone, Debug)]
+pub struct GenerateArgs {
+    /// The seed to generate from. The same seed always gives the same input.
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+    /// How many lines, games, schematic rows, cards, or ranges in each
+    /// almanac map to generate.
+    #[arg(long, default_value_t = 1000)]
+    pub size: usize,
+    /// How long each line is: characters per calibration line or schematic
+    /// row, handfuls per game, numbers per card, or seed ranges in an almanac.
+    #[arg(long, default_value_t = 100)]
+    pub width: usize,
+    /// The fraction of schematic cells that are symbols.
+    #[arg(long, default_value_t = 0.1)]
+    pub density: f64,
+}
+
+/// Generate a synthetic input for a day.
+pub fn generate(year: u16, day: u8, args: &GenerateArgs) -> Result<String> {
+    let GenerateArgs {
+        seed,
+        size,
+        width,
+        density,
+    } = *args;
+
+    match (year, day) {
+        (2023, 1) => Ok(day_2023_1::generate(seed, size, width)),
+        (2023, 2) => Ok(day_2023_2::generate(seed, size, width)),
+        (2023, 3) => Ok(day_2023_3::generate(seed, width, size, density)),
+        (2023, 4) => Ok(day_2023_4::generate(seed, size, width)),
+        (2023, 5) => Ok(day_2023_5::generate(seed, width, size)),
+        _ => Err(AocError::Unimplemented { year, day }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn generates_inputs_that_parse() {
+        let args = GenerateArgs {
+            seed: 1,
+            size: 50,
+            width: 30,
+            density: 0.2,
+        };
+        let input = |day| generate(2023, day, &args).unwrap();
+
+        let lines = day_2023_1::Calibration::parse(&input(1)).unwrap();
+        assert_eq!(lines.len(), 50);
+        assert!(day_2023_1::Calibration::part_one(&lines).is_ok());
+        assert!(day_2023_1::Calibration::part_two(&lines).is_ok());
+
+        let games = <day_2023_2::Game as Solution>::parse(&input(2)).unwrap();
+        assert_eq!(games.len(), 50);
+        assert!(games.iter().all(|g| (1..=30).contains(&g.handfuls.len())));
+
+        let schematic = input(3);
+        assert!(schematic.lines().all(|line| line.len() == 30));
+        let schematic = <day_2023_3::Schematic as Solution>::parse(&schematic).unwrap();
+        assert_eq!(schematic.grid.height(), 50);
+        assert!(day_2023_3::Schematic::part_two(&schematic).is_ok());
+
+        let cards = <day_2023_4::Card as Solution>::parse(&input(4)).unwrap();
+        assert_eq!(cards.len(), 50);
+        assert!(cards.iter().all(|c| c.actual_numbers.len() == 30));
+        assert!(day_2023_4::Card::part_two(&cards).is_ok());
+
+        let almanac = <day_2023_5::Almanac as Solution>::parse(&input(5)).unwrap();
+        assert_eq!(almanac.seeds.len(), 30);
+        assert!(almanac.seed_soil_map.ranges.len() <= 50);
+        assert!(day_2023_5::Almanac::part_one(&almanac).is_ok());
+    }
+
+    #[test]
+    fn reproducible_from_the_seed() {
+        let args = |seed| GenerateArgs {
+            seed,
+            size: 20,
+            width: 20,
+            density: 0.1,
+        };
+
+        for day in 1..=5 {
+            let input = generate(2023, day, &args(7)).unwrap();
+            assert_eq!(input, generate(2023, day, &args(7)).unwrap());
+            assert_ne!(input, generate(2023, day, &args(8)).unwrap());
+        }
+        assert!(generate(2023, 6, &args(7)).is_err());
+    }
+}
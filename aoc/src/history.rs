@@ -0,0 +1,175 @@
+use aoc_common::history::Record;
+use std::time::Duration;
+
+/// The recorded runs of one day against one input, oldest first.
+///
+/// Only runs of both parts are included, since a run of a single part isn't
+/// comparable with them.
+#[derive(Clone, Debug)]
+pub struct Trend<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub input: &'a str,
+    pub runs: Vec<&'a Record>,
+}
+impl Trend<'_> {
+    /// The fastest run.
+    pub fn best(&self) -> &Record {
+        self.runs
+            .iter()
+            .min_by_key(|r| r.report.timings.total())
+            .expect("trends always have a run")
+    }
+    /// How much slower `run` was than the best run, as a percentage.
+    pub fn slowdown(&self, run: &Record) -> f64 {
+        percent(
+            self.best().report.timings.total(),
+            run.report.timings.total(),
+        )
+    }
+    /// Whether the latest run is more than `threshold` percent slower than
+    /// the best.
+    pub fn regressed(&self, threshold: f64) -> bool {
+        let latest = self.runs.last().expect("trends always have a run");
+        self.slowdown(latest) > threshold
+    }
+}
+
+/// Group the records into a trend per day and input, ordered by day.
+pub fn trends(records: &[Record]) -> Vec<Trend<'_>> {
+    let mut trends: Vec<Trend> = vec![];
+
+    for record in records {
+        let timings = &record.report.timings;
+        if timings.part1.is_none() || timings.part2.is_none() {
+            continue;
+        }
+
+        let (year, day) = (record.report.year, record.report.day);
+        match trends
+            .iter_mut()
+            .find(|t| (t.year, t.day, t.input) == (year, day, record.input.as_str()))
+        {
+            Some(trend) => trend.runs.push(record),
+            None => trends.push(Trend {
+                year,
+                day,
+                input: &record.input,
+                runs: vec![record],
+            }),
+        }
+    }
+
+    trends.sort_by_key(|t| (t.year, t.day));
+    trends
+}
+
+/// Print the latest `last` runs of each trend, with how each compares to the
+/// best run and the run before it, flagging runs more than `threshold`
+/// percent slower than the best.
+pub fn print_trends(trends: &[Trend], threshold: f64, last: usize) {
+    for (i, trend) in trends.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let best = trend.best();
+        println!(
+            "{} day {}, input {} (best {} ms at {})",
+            trend.year,
+            trend.day,
+            trend.input,
+            ms(best.report.timings.total()),
+            commit(best),
+        );
+        println!(
+            "  {:<20} {:>10} {:>10} {:>10}",
+            "commit", "total ms", "vs best", "vs prev"
+        );
+
+        let skip = trend.runs.len().saturating_sub(last);
+        for (j, run) in trend.runs.iter().enumerate().skip(skip) {
+            let total = run.report.timings.total();
+            let previous = match j {
+                0 => "-".to_string(),
+                j => format!(
+                    "{:+.1}%",
+                    percent(trend.runs[j - 1].report.timings.total(), total)
+                ),
+            };
+            let slowdown = trend.slowdown(run);
+            println!(
+                "  {:<20} {:>10} {:>10} {:>10}{}",
+                commit(run),
+                ms(total),
+                format!("{slowdown:+.1}%"),
+                previous,
+                if slowdown > threshold { "  SLOWER" } else { "" },
+            );
+        }
+    }
+}
+
+fn commit(run: &Record) -> &str {
+    run.commit.as_deref().unwrap_or("unknown")
+}
+
+fn ms(d: Duration) -> String {
+    format!("{:.3}", d.as_secs_f64() * 1000.0)
+}
+
+/// How much longer `time` is than `base`, as a percentage.
+fn percent(base: Duration, time: Duration) -> f64 {
+    if base.is_zero() {
+        return 0.0;
+    }
+    (time.as_secs_f64() / base.as_secs_f64() - 1.0) * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Report, Timings};
+
+    fn record(day: u8, input: &str, part1: u64, part2: Option<u64>) -> Record {
+        Record {
+            commit: None,
+            input: input.into(),
+            recorded_at: 0,
+            report: Report {
+                year: 2023,
+                day,
+                part1: None,
+                part2: None,
+                timings: Timings {
+                    part1: Some(Duration::from_millis(part1)),
+                    part2: part2.map(Duration::from_millis),
+                    ..Timings::default()
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn trends_by_day_and_input() {
+        let records = [
+            record(5, "a", 10, Some(10)),
+            record(1, "a", 1, Some(1)),
+            record(5, "b", 30, Some(30)),
+            record(5, "a", 5, None),
+            record(5, "a", 11, Some(11)),
+        ];
+
+        let trends = trends(&records);
+        let keys: Vec<_> = trends
+            .iter()
+            .map(|t| (t.day, t.input, t.runs.len()))
+            .collect();
+        assert_eq!(keys, [(1, "a", 1), (5, "a", 2), (5, "b", 1)]);
+
+        let day_5 = &trends[1];
+        assert_eq!(day_5.best(), &records[0]);
+        assert!((day_5.slowdown(&records[4]) - 10.0).abs() < 1e-9);
+        assert!(day_5.regressed(5.0));
+        assert!(!day_5.regressed(10.5));
+    }
+}
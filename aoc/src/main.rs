@@ -0,0 +1,263 @@
+use aoc_common::{cli, log::LogArgs, AocError, Part};
+use clap::{Parser, Subcommand};
+use std::process::ExitCode;
+
+mod client;
+mod fetch;
+mod generate;
+mod history;
+#[cfg(test)]
+mod mock;
+mod new;
+mod registry;
+mod submit;
+mod verify;
+mod watch;
+mod year;
+
+#[derive(Parser, Debug)]
+#[command(about = "Run Advent of Code solutions")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run a day's solution, or every day of a year with `--all`.
+    Run {
+        year: u16,
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Run every implemented day of the year at once, and summarize their
+        /// answers and timings. Days slow each other down when run at once,
+        /// so their timings can't be recorded.
+        #[arg(long, conflicts_with_all = ["day", "input", "stream", "record"])]
+        all: bool,
+        /// Print the year's summary as a Markdown table.
+        #[arg(long, requires = "all", conflicts_with = "format")]
+        markdown: bool,
+        #[command(flatten)]
+        args: cli::RunArgs,
+    },
+    /// Run every day against its input.txt and check the answers against
+    /// its answers.txt.
+    Verify {
+        /// Only verify days from this year.
+        year: Option<u16>,
+        /// Only verify this day.
+        day: Option<u8>,
+    },
+    /// Download a day's puzzle input into the cache, and into the day's
+    /// input.txt if it doesn't have one yet.
+    Fetch {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        client: client::ClientArgs,
+    },
+    /// Submit an answer to the puzzle server. Answers already known to be
+    /// wrong aren't submitted again.
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+        /// The answer to submit. Defaults to the day's answer for its input.txt.
+        answer: Option<String>,
+        #[command(flatten)]
+        client: client::ClientArgs,
+    },
+    /// Rebuild and re-run a day whenever its source or input changes, showing
+    /// how the answers changed and whether they're the expected ones.
+    Watch {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        args: cli::InputArgs,
+    },
+    /// Create a new day's crate from the template and register it.
+    New {
+        year: u16,
+        day: u8,
+        /// Depend on nom for parsing.
+        #[arg(long)]
+        nom: bool,
+    },
+    /// Print a synthetic input for stress testing a day, reproducible from
+    /// its seed.
+    Generate {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        args: generate::GenerateArgs,
+    },
+    /// Show how each day's recorded run times have changed, flagging days
+    /// that got slower. Runs are recorded with `aoc run --record`.
+    History {
+        /// Only show days from this year.
+        year: Option<u16>,
+        /// Only show this day.
+        day: Option<u8>,
+        /// Flag runs more than this many percent slower than the best run.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// How many of the latest runs to show for each day.
+        #[arg(long, default_value_t = 10)]
+        last: usize,
+    },
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    args.log.init();
+
+    cli::exit_code(match args.command {
+        Command::Run {
+            year,
+            day: Some(day),
+            args,
+            ..
+        } => run(year, day, &args),
+        Command::Run {
+            year,
+            args,
+            markdown,
+            ..
+        } => run_all(year, &args, markdown),
+        Command::Verify { year, day } => verify(year, day),
+        Command::Fetch { year, day, client } => fetch(year, day, &client),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            client,
+        } => submit(year, day, part, answer, &client),
+        Command::Watch { year, day, args } => watch::watch(year, day, &args),
+        Command::New { year, day, nom } => {
+            new::generate(&aoc_common::paths::root(), year, day, nom).map(|dir| {
+                println!("{}", dir.display());
+                true
+            })
+        }
+        Command::Generate { year, day, args } => {
+            generate::generate(year, day, &args).map(|input| {
+                print!("{input}");
+                true
+            })
+        }
+        Command::History {
+            year,
+            day,
+            threshold,
+            last,
+        } => history(year, day, threshold, last),
+    })
+}
+
+fn run(year: u16, day: u8, args: &cli::RunArgs) -> aoc_common::Result<bool> {
+    let solution = registry::find(year, day).ok_or(AocError::Unimplemented { year, day })?;
+    cli::run(solution, args)
+}
+
+/// Run every implemented day of `year` at once, then print a summary of
+/// their answers and timings.
+fn run_all(year: u16, args: &cli::RunArgs, markdown: bool) -> aoc_common::Result<bool> {
+    let outcomes = year::run(year, args);
+    if outcomes.is_empty() {
+        return Err(AocError::Usage(format!("no days implemented for {year}")));
+    }
+    Ok(year::report(&outcomes, args, markdown))
+}
+
+fn verify(year: Option<u16>, day: Option<u8>) -> aoc_common::Result<bool> {
+    let days = registry::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|year| d.year == year))
+        .filter(|d| day.is_none_or(|day| d.day == day));
+
+    let verifications = days
+        .map(verify::verify)
+        .collect::<aoc_common::Result<Vec<_>>>()?;
+    verify::print_table(&verifications);
+
+    Ok(!verifications
+        .iter()
+        .any(|v| v.statuses.contains(&verify::Status::Fail)))
+}
+
+fn fetch(year: u16, day: u8, args: &client::ClientArgs) -> aoc_common::Result<bool> {
+    let client = client::Client::new(args);
+    let (path, _) = fetch::fetch(&client, &aoc_common::paths::cache(), year, day)?;
+
+    match fetch::install(&path, year, day)? {
+        Some(input) => println!("{}", input.display()),
+        None => println!("{}", path.display()),
+    }
+    Ok(true)
+}
+
+fn submit(
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    args: &client::ClientArgs,
+) -> aoc_common::Result<bool> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution =
+                registry::find(year, day).ok_or(AocError::Unimplemented { year, day })?;
+            let input = aoc_common::input::Source::Default.read(year, day)?;
+            let answers = solution.run(&input, Some(part))?;
+            match answers.get(part) {
+                Some(Ok(answer)) => answer.clone(),
+                Some(Err(e)) => {
+                    eprint!("{}", aoc_common::diagnostic::render(e, &input));
+                    return Ok(false);
+                }
+                None => unreachable!("the part was run"),
+            }
+        }
+    };
+
+    let client = client::Client::new(args);
+    let guesses = aoc_common::paths::cache().join("guesses.jsonl");
+    match submit::submit(&client, &guesses, year, day, part, &answer)? {
+        Ok(outcome) => {
+            println!("{answer}: {outcome}");
+            Ok(outcome == submit::Outcome::Right)
+        }
+        Err(reason) => {
+            eprintln!("not submitting {answer}: {reason}");
+            Ok(false)
+        }
+    }
+}
+
+/// Print the recorded history, returning whether no day's latest run has
+/// regressed.
+fn history(
+    year: Option<u16>,
+    day: Option<u8>,
+    threshold: f64,
+    last: usize,
+) -> aoc_common::Result<bool> {
+    let records = aoc_common::history::load(&aoc_common::history::path())?;
+    let trends: Vec<_> = history::trends(&records)
+        .into_iter()
+        .filter(|t| year.is_none_or(|year| t.year == year))
+        .filter(|t| day.is_none_or(|day| t.day == day))
+        .collect();
+
+    if trends.is_empty() {
+        println!("no recorded runs; record some with `aoc run --record`");
+        return Ok(true);
+    }
+    history::print_trends(&trends, threshold, last);
+
+    Ok(!trends.iter().any(|t| t.regressed(threshold)))
+}
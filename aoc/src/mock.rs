@@ -0,0 +1,48 @@
+//! A stand-in for the puzzle server, for testing the client.
+
+use std::thread::{self, JoinHandle};
+
+/// A request the server received.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// Serve `requests` requests on a local port, answering each with the status
+/// and body `respond` gives for it. Returns the server's base URL and a handle
+/// that yields the requests it received.
+pub fn serve(
+    requests: usize,
+    respond: fn(&Request) -> (u16, &'static str),
+) -> (String, JoinHandle<Vec<Request>>) {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr());
+
+    let handle = thread::spawn(move || {
+        let mut received = vec![];
+        for mut request in server.incoming_requests().take(requests) {
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let seen = Request {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                cookie: request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string()),
+                body,
+            };
+
+            let (status, body) = respond(&seen);
+            let response = tiny_http::Response::from_string(body).with_status_code(status);
+            request.respond(response).unwrap();
+            received.push(seen);
+        }
+        received
+    });
+    (base_url, handle)
+}
@@ -0,0 +1,198 @@
+use aoc_common::{AocError, Result};
+use std::path::{Path, PathBuf};
+
+/// The template for a new day, relative to the repository root. Every file in
+/// it ending in `.tmpl` is copied into the new day without that extension,
+/// with `{{year}}` and `{{day}}` filled in, and `{{dependencies}}` replaced by
+/// any extra dependencies.
+pub const TEMPLATE: &str = "template/day";
+
+/// Create a new day's crate from the template under `root`, and register it
+/// in the workspace and with the `aoc` runner. Returns the crate's directory.
+pub fn generate(root: &Path, year: u16, day: u8, nom: bool) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(invalid(format!(
+            "there is no day {day}; days go from 1 to 25"
+        )));
+    }
+    let member = format!("{year}/day-{day}");
+    let dir = root.join(&member);
+    if dir.exists() {
+        return Err(invalid(format!("{} already exists", dir.display())));
+    }
+
+    // Work out every change before writing anything, so a failure leaves the
+    // repository as it was.
+    let workspace = root.join("Cargo.toml");
+    let workspace_toml = add_member(&std::fs::read_to_string(&workspace)?, &member)?;
+    let runner = root.join("aoc/Cargo.toml");
+    let runner_toml = add_dependency(&std::fs::read_to_string(&runner)?, year, day)?;
+    let registry = root.join("aoc/src/registry.rs");
+    let registry_rs = add_to_registry(&std::fs::read_to_string(&registry)?, year, day)?;
+
+    let dependencies = if nom { "nom.workspace = true\n" } else { "" };
+    let template = root.join(TEMPLATE);
+    for (path, contents) in files(&template)? {
+        let target = dir.join(
+            path.strip_prefix(&template)
+                .expect("files are in the template"),
+        );
+        let contents = contents
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day}}", &day.to_string())
+            .replace("{{dependencies}}\n", dependencies);
+        std::fs::create_dir_all(target.parent().expect("files are in a directory"))?;
+        std::fs::write(target.with_extension(""), contents)?;
+    }
+
+    std::fs::write(workspace, workspace_toml)?;
+    std::fs::write(runner, runner_toml)?;
+    std::fs::write(registry, registry_rs)?;
+    Ok(dir)
+}
+
+/// Every `.tmpl` file under `dir`, with its contents.
+fn files(dir: &Path) -> Result<Vec<(PathBuf, String)>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(self::files(&path)?);
+        } else if path.extension().is_some_and(|e| e == "tmpl") {
+            let contents = std::fs::read_to_string(&path)?;
+            files.push((path, contents));
+        }
+    }
+    Ok(files)
+}
+
+/// Add a member to the workspace's `Cargo.toml`.
+fn add_member(cargo_toml: &str, member: &str) -> Result<String> {
+    let start = cargo_toml
+        .find("members = [")
+        .ok_or_else(|| invalid("no workspace members in Cargo.toml"))?;
+    let end = start
+        + cargo_toml[start..]
+            .find("\n]")
+            .ok_or_else(|| invalid("the workspace members in Cargo.toml aren't one per line"))?;
+
+    Ok(format!(
+        "{}\n    \"{member}\",{}",
+        &cargo_toml[..end],
+        &cargo_toml[end..]
+    ))
+}
+
+/// Add a day's crate to the dependencies of the runner's `Cargo.toml`, after
+/// the other days. Crates are named `day-{year}-{day}`, so that every year
+/// can have a day 1.
+fn add_dependency(cargo_toml: &str, year: u16, day: u8) -> Result<String> {
+    let name = format!("day-{year}-{day}");
+    if cargo_toml.contains(&format!("\n{name} = ")) {
+        return Err(invalid(format!(
+            "the runner already has a crate named {name}"
+        )));
+    }
+    let last_day = cargo_toml
+        .rfind("\nday-")
+        .ok_or_else(|| invalid("no days in aoc/Cargo.toml"))?;
+    let end = last_day + 1 + cargo_toml[last_day + 1..].find('\n').unwrap_or(0);
+
+    Ok(format!(
+        "{}\n{name} = {{ path = \"../{year}/day-{day}\" }}{}",
+        &cargo_toml[..end],
+        &cargo_toml[end..]
+    ))
+}
+
+/// Add a day's solution to the end of the runner's registry.
+fn add_to_registry(registry: &str, year: u16, day: u8) -> Result<String> {
+    let end = registry
+        .find("\n];")
+        .ok_or_else(|| invalid("no list of days in aoc/src/registry.rs"))?;
+
+    Ok(format!(
+        "{}\n    Day::of::<day_{year}_{day}::Puzzle>(),{}",
+        &registry[..end],
+        &registry[end..]
+    ))
+}
+
+fn invalid(message: impl Into<String>) -> AocError {
+    AocError::Usage(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_the_day() {
+        let workspace = "[workspace]\nmembers = [\n    \"aoc\",\n    \"2023/day-5\",\n]\n";
+        assert_eq!(
+            add_member(workspace, "2023/day-6").unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2023/day-5\",\n    \"2023/day-6\",\n]\n"
+        );
+
+        let runner = "[dependencies]\nclap.workspace = true\nday-2023-5 = { path = \"../2023/day-5\" }\nureq.workspace = true\n";
+        assert_eq!(
+            add_dependency(runner, 2023, 6).unwrap(),
+            "[dependencies]\nclap.workspace = true\nday-2023-5 = { path = \"../2023/day-5\" }\nday-2023-6 = { path = \"../2023/day-6\" }\nureq.workspace = true\n"
+        );
+        assert!(add_dependency(runner, 2023, 5).is_err());
+        assert!(add_dependency(runner, 2024, 5)
+            .unwrap()
+            .contains("\nday-2024-5 = { path = \"../2024/day-5\" }\n"));
+
+        let registry = "pub const DAYS: &[Day] = &[\n    Day::of::<day_2023_5::Almanac>(),\n];\n";
+        assert_eq!(
+            add_to_registry(registry, 2023, 6).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    Day::of::<day_2023_5::Almanac>(),\n    Day::of::<day_2023_6::Puzzle>(),\n];\n"
+        );
+    }
+
+    #[test]
+    fn generates_from_the_template() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs"] {
+            std::fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            std::fs::copy(repo.join(file), root.join(file)).unwrap();
+        }
+        for (path, _) in files(&repo.join(TEMPLATE)).unwrap() {
+            let target = root.join(path.strip_prefix(repo).unwrap());
+            std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+            std::fs::copy(&path, target).unwrap();
+        }
+
+        let dir = generate(&root, 2023, 25, true).unwrap();
+        let read = |file: &str| std::fs::read_to_string(dir.join(file)).unwrap();
+        let cargo_toml = read("Cargo.toml");
+        let lib = read("src/lib.rs");
+        let main = read("src/main.rs");
+        let examples = read("tests/examples.rs");
+        let test = read("test.txt");
+        let again = generate(&root, 2023, 25, false);
+        // The same day of another year is a different crate.
+        let next_year = generate(&root, 2024, 25, false).unwrap();
+        let next_year_toml = std::fs::read_to_string(next_year.join("Cargo.toml")).unwrap();
+        let workspace = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let runner = std::fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        let registry = std::fs::read_to_string(root.join("aoc/src/registry.rs")).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert!(cargo_toml.contains("name = \"day-2023-25\""));
+        assert!(cargo_toml.ends_with("aoc-common.workspace = true\nnom.workspace = true\n"));
+        assert!(lib.contains("const YEAR: u16 = 2023;\n    const DAY: u8 = 25;"));
+        assert!(main.contains("day_2023_25::Puzzle"));
+        assert!(examples.contains("use day_2023_25::Puzzle;"));
+        assert_eq!(test, "");
+        assert!(again.is_err());
+
+        assert!(next_year_toml.contains("name = \"day-2024-25\""));
+        assert!(workspace.contains("\"2023/day-25\",\n    \"2024/day-25\",\n]"));
+        assert!(runner.contains("day-2023-25 = { path = \"../2023/day-25\" }\nday-2024-25 = "));
+        assert!(registry.contains("day_2023_25::Puzzle>(),\n    Day::of::<day_2024_25::Puzzle>(),"));
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::Day;
+
+/// Every implemented day, in order.
+pub const DAYS: &[Day] = &[
+    Day::streaming::<day_2023_1::Calibration>(),
+    Day::streaming::<day_2023_2::Game>(),
+    Day::of::<day_2023_3::Schematic>(),
+    Day::streaming::<day_2023_4::Card>(),
+    Day::of::<day_2023_5::Almanac>(),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
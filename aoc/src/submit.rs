@@ -0,0 +1,332 @@
+use crate::client::Client;
+use aoc_common::{AocError, Part, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// What the puzzle server said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer came too soon after the last one, and wasn't checked.
+    Wait {
+        /// How long until another answer can be submitted, in seconds.
+        seconds: Option<u64>,
+    },
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+}
+impl Outcome {
+    /// Work out the outcome from the page the server responds with.
+    pub fn parse(page: &str) -> Option<Outcome> {
+        if page.contains("That's the right answer") {
+            Some(Outcome::Right)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Some(Outcome::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Outcome::TooLow)
+            } else {
+                Some(Outcome::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Some(Outcome::Wait {
+                seconds: wait_seconds(page),
+            })
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Outcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "right"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wait { seconds: Some(s) } => write!(f, "too soon, wait {s}s"),
+            Outcome::Wait { seconds: None } => write!(f, "too soon, wait a while"),
+            Outcome::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+        }
+    }
+}
+
+/// Read the wait from "You have 1m 5s left to wait".
+fn wait_seconds(page: &str) -> Option<u64> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+
+    wait.split_whitespace().try_fold(0, |seconds, part| {
+        let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        match unit {
+            "h" => Some(seconds + n * 3600),
+            "m" => Some(seconds + n * 60),
+            "s" => Some(seconds + n),
+            _ => None,
+        }
+    })
+}
+
+/// One answer submitted for a part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// When it was submitted, in seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Every answer submitted so far, kept as JSON lines.
+#[derive(Clone, Debug, Default)]
+pub struct Guesses(pub Vec<Guess>);
+impl Guesses {
+    pub fn load(path: &Path) -> Result<Guesses> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Guesses::default()),
+            Err(e) => return Err(e.into()),
+        };
+
+        text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|e| {
+                    AocError::Io(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("{}: {e}", path.display()),
+                    ))
+                })
+            })
+            .collect::<Result<_>>()
+            .map(Guesses)
+    }
+    pub fn append(path: &Path, guess: &Guess) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let line = serde_json::to_string(guess).expect("guesses are always valid JSON");
+        writeln!(file, "{line}")?;
+        Ok(())
+    }
+    /// Explain why `answer` shouldn't be submitted, if it's already known to
+    /// be wrong, the part is already solved, or the server asked us to wait.
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str, now: u64) -> Option<String> {
+        let guesses: Vec<&Guess> = self
+            .0
+            .iter()
+            .filter(|g| (g.year, g.day, g.part) == (year, day, part.number()))
+            .collect();
+        let number = answer.parse::<i128>().ok();
+
+        for guess in &guesses {
+            match &guess.outcome {
+                Outcome::Right if guess.answer == answer => {
+                    return Some(format!("{answer} is already known to be right"));
+                }
+                Outcome::Right => {
+                    return Some(format!("already solved with {}", guess.answer));
+                }
+                Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow if guess.answer == answer => {
+                    return Some(format!("{answer} was already guessed and was wrong"));
+                }
+                Outcome::TooHigh => {
+                    if let (Some(n), Ok(high)) = (number, guess.answer.parse::<i128>()) {
+                        if n >= high {
+                            return Some(format!("{} was already too high", guess.answer));
+                        }
+                    }
+                }
+                Outcome::TooLow => {
+                    if let (Some(n), Ok(low)) = (number, guess.answer.parse::<i128>()) {
+                        if n <= low {
+                            return Some(format!("{} was already too low", guess.answer));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Waits apply to every puzzle, not just this one.
+        let wait_until = self
+            .0
+            .iter()
+            .filter_map(|g| match g.outcome {
+                Outcome::Wait { seconds } => Some(g.submitted_at + seconds.unwrap_or(60)),
+                _ => None,
+            })
+            .max()?;
+        (now < wait_until).then(|| format!("wait {}s before submitting again", wait_until - now))
+    }
+}
+
+/// Submit an answer unless the recorded guesses show it's pointless,
+/// recording the outcome. Returns `Err` with the reason if it wasn't
+/// submitted.
+pub fn submit(
+    client: &Client,
+    guesses_path: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<std::result::Result<Outcome, String>> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs();
+    if let Some(reason) = Guesses::load(guesses_path)?.check(year, day, part, answer, now) {
+        return Ok(Err(reason));
+    }
+
+    let page = client.submit(year, day, part, answer)?;
+    let outcome = Outcome::parse(&page).ok_or_else(|| {
+        AocError::Request("couldn't tell whether the answer was right from the response".into())
+    })?;
+
+    Guesses::append(
+        guesses_path,
+        &Guess {
+            year,
+            day,
+            part: part.number(),
+            answer: answer.to_string(),
+            outcome: outcome.clone(),
+            submitted_at: now,
+        },
+    )?;
+    Ok(Ok(outcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::ClientArgs, mock};
+
+    fn page(message: &str) -> String {
+        format!("<main>\n<article><p>{message}</p></article>\n</main>")
+    }
+
+    #[test]
+    fn outcomes() {
+        let parse = |message| Outcome::parse(&page(message));
+
+        assert_eq!(
+            parse("That's the right answer!  You are one gold star closer."),
+            Some(Outcome::Right)
+        );
+        assert_eq!(
+            parse("That's not the right answer.  If you're stuck, make sure you're using the full input data."),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too high.  Please wait one minute."),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low."),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+            Some(Outcome::Wait { seconds: Some(65) })
+        );
+        assert_eq!(
+            parse("You gave an answer too recently.  You have 38s left to wait."),
+            Some(Outcome::Wait { seconds: Some(38) })
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(parse("Something else entirely."), None);
+    }
+
+    fn guess(part: u8, answer: &str, outcome: Outcome) -> Guess {
+        Guess {
+            year: 2023,
+            day: 5,
+            part,
+            answer: answer.into(),
+            outcome,
+            submitted_at: 1000,
+        }
+    }
+
+    #[test]
+    fn refuses_pointless_guesses() {
+        let guesses = Guesses(vec![
+            guess(1, "40", Outcome::Wrong),
+            guess(1, "100", Outcome::TooHigh),
+            guess(1, "10", Outcome::TooLow),
+            guess(2, "46", Outcome::Right),
+        ]);
+        let check = |part, answer| guesses.check(2023, 5, part, answer, 2000);
+
+        assert!(check(Part::One, "40").is_some());
+        assert!(check(Part::One, "100").is_some());
+        assert!(check(Part::One, "150").is_some());
+        assert!(check(Part::One, "10").is_some());
+        assert!(check(Part::One, "3").is_some());
+        assert_eq!(check(Part::One, "35"), None);
+        assert_eq!(check(Part::One, "abc"), None);
+        assert!(check(Part::Two, "46").is_some());
+        assert!(check(Part::Two, "47").is_some());
+
+        let waiting = Guesses(vec![guess(1, "40", Outcome::Wait { seconds: Some(30) })]);
+        assert!(waiting.check(2023, 5, Part::One, "35", 1010).is_some());
+        assert_eq!(waiting.check(2023, 5, Part::One, "35", 1030), None);
+    }
+
+    #[test]
+    fn submits_and_records() {
+        let (base_url, server) = mock::serve(1, |_| {
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            )
+        });
+        let client = Client::new(&ClientArgs {
+            base_url,
+            session: Some("abc123".into()),
+        });
+        let path = std::env::temp_dir().join(format!("aoc-guesses-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let outcome = submit(&client, &path, 2023, 5, Part::Two, "50").unwrap();
+        assert_eq!(outcome, Ok(Outcome::TooHigh));
+
+        // Known to be too high now, so this doesn't reach the server.
+        let outcome = submit(&client, &path, 2023, 5, Part::Two, "60").unwrap();
+        assert!(outcome.is_err());
+
+        let guesses = Guesses::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(guesses.0.len(), 1);
+        assert_eq!(guesses.0[0].answer, "50");
+
+        let [request] = &server.join().unwrap()[..] else {
+            panic!("expected one request");
+        };
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url, "/2023/day/5/answer");
+        assert_eq!(request.cookie.as_deref(), Some("session=abc123"));
+        assert_eq!(request.body, "level=2&answer=50");
+    }
+}
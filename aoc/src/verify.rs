@@ -0,0 +1,139 @@
+use aoc_common::{
+    expected::{self, Expectation, Expected},
+    paths, Day, Part, Result,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+}
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// The result of checking one day's real input against its known answers.
+#[derive(Clone, Debug)]
+pub struct Verification {
+    pub year: u16,
+    pub day: u8,
+    pub statuses: [Status; 2],
+    /// Explanations for anything that didn't pass.
+    pub notes: Vec<String>,
+}
+
+/// Run a day against its `input.txt` and compare with its `answers.txt`.
+pub fn verify(day: &Day) -> Result<Verification> {
+    let dir = paths::day_dir(day.year, day.day);
+    let mut verification = Verification {
+        year: day.year,
+        day: day.day,
+        statuses: [Status::Unknown; 2],
+        notes: vec![],
+    };
+
+    let input_path = paths::input(day.year, day.day);
+    if !input_path.exists() {
+        verification.notes.push("no input.txt".into());
+        return Ok(verification);
+    }
+    let input = aoc_common::input::read_file(&input_path)?;
+
+    let Some(known) = expected::known_answers(&dir, &input)? else {
+        verification.notes.push(format!(
+            "no known answers for input {} in {}",
+            aoc_common::input::hash(&input),
+            expected::ANSWERS_FILE,
+        ));
+        return Ok(verification);
+    };
+
+    let answers = day.run(&input, None);
+    for (i, part) in [Part::One, Part::Two].into_iter().enumerate() {
+        let result = match &answers {
+            Ok(answers) => answers.get(part).map(|r| r.as_ref()),
+            Err(e) => Some(Err(e)),
+        };
+        let (status, note) = status(&known, part, result);
+
+        verification.statuses[i] = status;
+        if let Some(note) = note {
+            verification
+                .notes
+                .push(format!("part {}: {note}", part.number()));
+        }
+    }
+
+    Ok(verification)
+}
+
+fn status(
+    known: &Expectation,
+    part: Part,
+    result: Option<std::result::Result<&String, &aoc_common::AocError>>,
+) -> (Status, Option<String>) {
+    match known.get(part) {
+        Expected::Unchecked => (Status::Unknown, None),
+        expected => match expected.check(result) {
+            Ok(()) => (Status::Pass, None),
+            Err(mismatch) => (Status::Fail, Some(mismatch)),
+        },
+    }
+}
+
+/// Print the verifications as a table, followed by any notes.
+pub fn print_table(verifications: &[Verification]) {
+    println!("{:<6} {:<4} {:<8} part 2", "year", "day", "part 1");
+    for v in verifications {
+        println!(
+            "{:<6} {:<4} {:<8} {}",
+            v.year,
+            v.day,
+            v.statuses[0].to_string(),
+            v.statuses[1],
+        );
+    }
+
+    for v in verifications {
+        for note in &v.notes {
+            println!("{} day {}: {note}", v.year, v.day);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::AocError;
+
+    fn known(part_one: Expected, part_two: Expected) -> Expectation {
+        Expectation {
+            input: "0123456789abcdef".into(),
+            part_one,
+            part_two,
+        }
+    }
+
+    #[test]
+    fn statuses() {
+        let known = known(Expected::Answer("42".into()), Expected::Unchecked);
+        let right = "42".to_string();
+        let wrong = "41".to_string();
+        let error = AocError::NoAnswer("nothing".into());
+
+        assert_eq!(status(&known, Part::One, Some(Ok(&right))).0, Status::Pass);
+        assert_eq!(status(&known, Part::One, Some(Ok(&wrong))).0, Status::Fail);
+        assert_eq!(status(&known, Part::One, Some(Err(&error))).0, Status::Fail);
+        assert_eq!(
+            status(&known, Part::Two, Some(Ok(&right))).0,
+            Status::Unknown
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use aoc_common::{
+    cli::InputArgs,
+    expected::{self, Expectation, Expected},
+    input::Source,
+    paths, AocError, Part, Report, Result,
+};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+/// How long to wait for more changes after one arrives, so that saving
+/// several files at once only triggers one run.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Rebuild and re-run a day whenever its source or input changes, showing
+/// how its answers changed. Only returns if watching fails.
+pub fn watch(year: u16, day: u8, args: &InputArgs) -> Result<bool> {
+    if args.source() == Source::Stdin {
+        return Err(AocError::Usage(
+            "stdin can't be watched for changes; save the input to a file instead".into(),
+        ));
+    }
+    let dir = paths::day_dir(year, day);
+    if !dir.is_dir() {
+        return Err(AocError::Unimplemented { year, day });
+    }
+    let input = args
+        .source()
+        .path(year, day)
+        .expect("watched inputs are always files");
+    let input = std::path::absolute(&input)?;
+
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(watch_error)?;
+    watcher
+        .watch(&dir, RecursiveMode::Recursive)
+        .map_err(watch_error)?;
+    if !input.starts_with(&dir) {
+        watcher
+            .watch(&input, RecursiveMode::NonRecursive)
+            .map_err(watch_error)?;
+    }
+
+    let mut previous = run(year, day, args, &input, None);
+    loop {
+        let changed = changes(&events, &dir, &input)?;
+        println!();
+        for path in &changed {
+            let path = path.strip_prefix(&dir).unwrap_or(path);
+            println!("changed: {}", path.display());
+        }
+        // Keep comparing against the last run that worked.
+        previous = run(year, day, args, &input, previous.as_ref()).or(previous);
+    }
+}
+
+/// Wait for a watched file to change, then for any other changes made along
+/// with it, returning every changed file.
+fn changes(
+    events: &mpsc::Receiver<notify::Result<Event>>,
+    dir: &Path,
+    input: &Path,
+) -> Result<Vec<PathBuf>> {
+    let mut changed = vec![];
+
+    loop {
+        let event = if changed.is_empty() {
+            events.recv().map_err(|_| disconnected())?
+        } else {
+            match events.recv_timeout(DEBOUNCE) {
+                Ok(event) => event,
+                Err(mpsc::RecvTimeoutError::Timeout) => return Ok(changed),
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Err(disconnected()),
+            }
+        };
+        let event = event.map_err(watch_error)?;
+
+        // Reading the input to run the day counts as an access.
+        if matches!(event.kind, EventKind::Access(_)) {
+            continue;
+        }
+        for path in event.paths {
+            if is_watched(dir, input, &path) && !changed.contains(&path) {
+                changed.push(path);
+            }
+        }
+    }
+}
+
+/// Whether a change to `path` should re-run the day: its source, its
+/// manifest, the input, or the expected answers.
+fn is_watched(dir: &Path, input: &Path, path: &Path) -> bool {
+    let name = path.file_name().unwrap_or_default();
+    path == input
+        || (path.starts_with(dir.join("src")) && path.extension().is_some_and(|e| e == "rs"))
+        || (path.parent() == Some(dir)
+            && (name == "Cargo.toml"
+                || name == expected::EXAMPLES_FILE
+                || name == expected::ANSWERS_FILE))
+}
+
+/// Rebuild and run the day, printing its answers compared with the previous
+/// run and the expected answers. Returns the new report, or `None` if the day
+/// didn't build or run.
+fn run(
+    year: u16,
+    day: u8,
+    args: &InputArgs,
+    input: &Path,
+    previous: Option<&Report>,
+) -> Option<Report> {
+    let dir = paths::day_dir(year, day);
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or("cargo".into()));
+    command
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(dir.join("Cargo.toml"))
+        .args(["--", "--format", "json", "--input"])
+        .arg(input);
+    if let Some(part) = args.part {
+        command.args(["--part", &part.number().to_string()]);
+    }
+
+    let start = Instant::now();
+    // Build errors and failed parts go straight to stderr.
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("error: couldn't run cargo: {e}");
+            return None;
+        }
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let Some(report) = stdout
+        .lines()
+        .rev()
+        .find_map(|line| serde_json::from_str::<Report>(line).ok())
+    else {
+        eprintln!("error: no answers; see the errors above");
+        return None;
+    };
+
+    let expectation = match expectation(&dir, args, input) {
+        Ok(expectation) => expectation,
+        Err(e) => {
+            eprintln!("error: couldn't read the expected answers: {e}");
+            None
+        }
+    };
+    println!("ran in {:.1}s", start.elapsed().as_secs_f64());
+    for line in compare(previous, &report, expectation.as_ref(), args.part) {
+        println!("{line}");
+    }
+    Some(report)
+}
+
+/// The expected answers for the input: from `expected.txt` for examples, or
+/// `answers.txt` for real inputs.
+fn expectation(dir: &Path, args: &InputArgs, input: &Path) -> Result<Option<Expectation>> {
+    match args.source() {
+        Source::Example(_) => {
+            let path = dir.join(expected::EXAMPLES_FILE);
+            if !path.exists() {
+                return Ok(None);
+            }
+            let name = input.file_name().unwrap_or_default();
+            Ok(expected::load(path)?
+                .into_iter()
+                .find(|e| e.input == name.to_string_lossy()))
+        }
+        _ => expected::known_answers(dir, &aoc_common::input::read_file(input)?),
+    }
+}
+
+/// Describe each part's answer, how it changed since the previous run, and
+/// whether it's the expected answer.
+fn compare(
+    previous: Option<&Report>,
+    report: &Report,
+    expectation: Option<&Expectation>,
+    only: Option<Part>,
+) -> Vec<String> {
+    let answer = |report: &Report, part| match part {
+        Part::One => report.part1.clone(),
+        Part::Two => report.part2.clone(),
+    };
+    let show = |answer: &Option<String>| answer.clone().unwrap_or_else(|| "failed".into());
+
+    [Part::One, Part::Two]
+        .into_iter()
+        .filter(|&part| only.is_none_or(|only| only == part))
+        .map(|part| {
+            let now = answer(report, part);
+            let mut line = format!("part {}: {}", part.number(), show(&now));
+
+            match previous.map(|previous| answer(previous, part)) {
+                Some(before) if before != now => line += &format!(" (was {})", show(&before)),
+                Some(_) => line += " (unchanged)",
+                None => {}
+            }
+            match (expectation.map(|e| e.get(part)), &now) {
+                (None | Some(Expected::Unchecked), _) => {}
+                (Some(Expected::Answer(expected)), Some(now)) if expected == now => {
+                    line += ", as expected"
+                }
+                (Some(Expected::Failure), None) => line += ", failing as expected",
+                (Some(expected), _) => line += &format!(", but expected {expected}"),
+            }
+            line
+        })
+        .collect()
+}
+
+fn watch_error(e: notify::Error) -> AocError {
+    AocError::Io(std::io::Error::other(format!("watching for changes: {e}")))
+}
+
+fn disconnected() -> AocError {
+    AocError::Io(std::io::Error::other("stopped watching for changes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Timings;
+
+    fn report(part1: Option<&str>, part2: Option<&str>) -> Report {
+        Report {
+            year: 2023,
+            day: 3,
+            part1: part1.map(String::from),
+            part2: part2.map(String::from),
+            timings: Timings::default(),
+        }
+    }
+
+    #[test]
+    fn compares_answers() {
+        let expectation = Expectation {
+            input: "test.txt".into(),
+            part_one: Expected::Answer("4361".into()),
+            part_two: Expected::Answer("467835".into()),
+        };
+        let first = report(Some("4360"), None);
+        let second = report(Some("4361"), Some("467835"));
+
+        assert_eq!(
+            compare(None, &first, Some(&expectation), None),
+            [
+                "part 1: 4360, but expected 4361",
+                "part 2: failed, but expected 467835"
+            ]
+        );
+        assert_eq!(
+            compare(Some(&first), &second, Some(&expectation), None),
+            [
+                "part 1: 4361 (was 4360), as expected",
+                "part 2: 467835 (was failed), as expected"
+            ]
+        );
+        assert_eq!(
+            compare(Some(&second), &second, None, Some(Part::Two)),
+            ["part 2: 467835 (unchanged)"]
+        );
+    }
+
+    #[test]
+    fn stdin_cant_be_watched() {
+        let args = InputArgs {
+            input: Some("-".into()),
+            example: None,
+            part: None,
+        };
+        let error = watch(2023, 1, &args).unwrap_err();
+        assert!(matches!(&error, AocError::Usage(_)));
+        assert!(error.to_string().contains("stdin can't be watched"));
+    }
+
+    #[test]
+    fn watches_source_and_inputs() {
+        let dir = Path::new("/aoc/2023/day-3");
+        let input = dir.join("test.txt");
+        let watched = |path: &str| is_watched(dir, &input, &dir.join(path));
+
+        assert!(watched("test.txt"));
+        assert!(watched("src/lib.rs"));
+        assert!(watched("src/bin/other.rs"));
+        assert!(watched("Cargo.toml"));
+        assert!(watched("expected.txt"));
+        assert!(!watched("input.txt"));
+        assert!(!watched("src/.lib.rs.swp"));
+        assert!(!watched("benches/day_3.rs"));
+    }
+}
@@ -0,0 +1,438 @@
+use crate::registry;
+use aoc_common::{
+    cli::{self, Format, RunArgs},
+    diagnostic,
+    log::{self, Level},
+    Answers, AocError, Day, Part, Report, Result,
+};
+use rayon::prelude::*;
+use serde::Serialize;
+use std::{
+    any::Any,
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe, PanicHookInfo},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// How one day of a year went.
+pub struct Outcome {
+    pub day: &'static Day,
+    /// The input the day ran against, for showing its errors.
+    pub input: String,
+    pub answers: std::result::Result<Answers, Failure>,
+}
+
+/// Why a day has no answers at all.
+#[derive(Debug)]
+pub enum Failure {
+    /// Its input couldn't be read or parsed.
+    Error(AocError),
+    Panic(String),
+}
+
+/// Run every implemented day of `year` at the same time, in the order of the
+/// registry.
+pub fn run(year: u16, args: &RunArgs) -> Vec<Outcome> {
+    registry::DAYS
+        .par_iter()
+        .filter(|day| day.year == year)
+        .map(|day| {
+            let mut input = String::new();
+            let answers = catch(|| {
+                let start = Instant::now();
+                input = args.input.source().read(day.year, day.day)?;
+                let read = start.elapsed();
+
+                let mut answers = day.run(&input, args.input.part)?;
+                answers.timings.read = Some(read);
+                Ok(answers)
+            });
+            Outcome {
+                day,
+                input,
+                answers,
+            }
+        })
+        .collect()
+}
+
+/// Run `f`, turning a panic into a [`Failure`] so that one day can't take
+/// down the rest. The panic's message and where it happened go into the
+/// failure instead of being printed in the middle of the other days' output.
+///
+/// Panics on other threads, like those of a day that uses rayon itself, aren't
+/// printed either while any day is running. Only their message reaches the
+/// day's thread, so its failure doesn't say where they happened.
+fn catch<T>(f: impl FnOnce() -> Result<T>) -> std::result::Result<T, Failure> {
+    let hook = CaptureHook::install();
+    // A panic caught on this thread while it ran a day's rayon tasks.
+    CAUGHT.take();
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    drop(hook);
+
+    match result {
+        Ok(result) => result.map_err(Failure::Error),
+        Err(payload) => {
+            let message = CAUGHT
+                .take()
+                .unwrap_or_else(|| panic_message(payload.as_ref()));
+            Err(Failure::Panic(message))
+        }
+    }
+}
+
+type Hook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send>;
+
+thread_local! {
+    /// The message of the last panic on this thread while any [`catch`] was
+    /// running.
+    static CAUGHT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// How many [`catch`]es are running, on any thread. They can nest: a thread
+/// waiting on its day's rayon tasks runs other days' meanwhile.
+static CATCHING: AtomicUsize = AtomicUsize::new(0);
+
+/// The hook to put back once no [`catch`] is running. Days run on several
+/// threads at once, so the hook is only swapped by the first to start and the
+/// last to finish.
+static HOOKS: Mutex<Option<Arc<Hook>>> = Mutex::new(None);
+
+/// The panic hook used while any [`catch`] is running, which keeps the
+/// message of a panic and leaves any panic after the last one to the old hook.
+struct CaptureHook;
+impl CaptureHook {
+    fn install() -> CaptureHook {
+        let mut hooks = HOOKS.lock().unwrap_or_else(|e| e.into_inner());
+        if CATCHING.load(Ordering::SeqCst) == 0 {
+            let previous = Arc::new(panic::take_hook());
+            let fallback = previous.clone();
+            panic::set_hook(Box::new(move |info| {
+                if CATCHING.load(Ordering::SeqCst) > 0 {
+                    let message = panic_message(info.payload());
+                    CAUGHT.set(Some(match info.location() {
+                        Some(location) => format!("{message} at {location}"),
+                        None => message,
+                    }));
+                } else {
+                    fallback(info);
+                }
+            }));
+            *hooks = Some(previous);
+        }
+        CATCHING.fetch_add(1, Ordering::SeqCst);
+        CaptureHook
+    }
+}
+impl Drop for CaptureHook {
+    fn drop(&mut self) {
+        let mut hooks = HOOKS.lock().unwrap_or_else(|e| e.into_inner());
+        if CATCHING.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // Dropping our hook drops its copy of the old one.
+            drop(panic::take_hook());
+            if let Some(previous) = hooks.take().and_then(Arc::into_inner) {
+                panic::set_hook(previous);
+            }
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".into()
+    }
+}
+
+/// Print the outcomes in the chosen format, with every error after them on
+/// stderr. Returns whether every day succeeded.
+pub fn report(outcomes: &[Outcome], args: &RunArgs, markdown: bool) -> bool {
+    match args.format {
+        Format::Human => print!("{}", table(outcomes, markdown)),
+        Format::Json => {
+            for outcome in outcomes {
+                println!(
+                    "{}",
+                    serde_json::to_string(&entry(outcome)).expect("reports are always valid JSON")
+                );
+            }
+        }
+    }
+    if args.timings && args.format == Format::Human && log::level() != Level::Quiet {
+        let timings: Vec<_> = outcomes
+            .iter()
+            .filter_map(|o| Some((o.day, o.answers.as_ref().ok()?.timings)))
+            .collect();
+        print!("\n{}", cli::timing_table(&timings));
+    }
+
+    let mut success = true;
+    for outcome in outcomes {
+        let (year, day) = (outcome.day.year, outcome.day.day);
+        match &outcome.answers {
+            Ok(answers) => {
+                for part in [Part::One, Part::Two] {
+                    if let Some(Err(e)) = answers.get(part) {
+                        let error = diagnostic::render(e, &outcome.input);
+                        eprint!("{year} day {day} part {} failed: {error}", part.number());
+                        success = false;
+                    }
+                }
+            }
+            Err(Failure::Error(e)) => {
+                let error = diagnostic::render(e, &outcome.input);
+                eprint!("{year} day {day} failed: {error}");
+                success = false;
+            }
+            Err(Failure::Panic(message)) => {
+                eprintln!("{year} day {day} panicked: {message}");
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+/// A day's report as printed with `--format json`, with why it failed if
+/// it has no answers at all.
+#[derive(Serialize)]
+struct Entry {
+    #[serde(flatten)]
+    report: Report,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+fn entry(outcome: &Outcome) -> Entry {
+    let (answers, error) = match &outcome.answers {
+        Ok(answers) => (clone(answers), None),
+        Err(Failure::Error(e)) => (Answers::default(), Some(e.to_string())),
+        Err(Failure::Panic(message)) => (Answers::default(), Some(format!("panicked: {message}"))),
+    };
+    let (report, _) = Report::new(outcome.day, answers);
+    Entry { report, error }
+}
+
+/// Answers hold errors, which can't be cloned, so keep just the answers.
+fn clone(answers: &Answers) -> Answers {
+    let answer = |part: &Option<Result<String>>| match part {
+        Some(Ok(answer)) => Some(Ok(answer.clone())),
+        Some(Err(e)) => Some(Err(AocError::NoAnswer(e.to_string()))),
+        None => None,
+    };
+    Answers {
+        part_one: answer(&answers.part_one),
+        part_two: answer(&answers.part_two),
+        timings: answers.timings,
+    }
+}
+
+/// A summary of the year: each day's answers and how long it took, and the
+/// total time. As Markdown, it's ready to paste into a README.
+pub fn table(outcomes: &[Outcome], markdown: bool) -> String {
+    let ms = |d: Duration| format!("{:.3}", d.as_secs_f64() * 1000.0);
+    let mut rows: Vec<[String; 5]> = outcomes
+        .iter()
+        .map(|outcome| {
+            let (part_one, part_two, time) = match &outcome.answers {
+                Ok(answers) => (
+                    cell(answers.part_one.as_ref()),
+                    cell(answers.part_two.as_ref()),
+                    ms(answers.timings.total()),
+                ),
+                Err(Failure::Error(_)) => ("failed".into(), "failed".into(), "-".into()),
+                Err(Failure::Panic(_)) => ("panicked".into(), "panicked".into(), "-".into()),
+            };
+            [
+                outcome.day.year.to_string(),
+                outcome.day.day.to_string(),
+                part_one,
+                part_two,
+                time,
+            ]
+        })
+        .collect();
+    let total = outcomes
+        .iter()
+        .filter_map(|o| Some(o.answers.as_ref().ok()?.timings.total()))
+        .sum();
+    rows.push(["total".into(), "".into(), "".into(), "".into(), ms(total)]);
+
+    let header = ["year", "day", "part 1", "part 2", "time (ms)"].map(String::from);
+    if markdown {
+        let mut table = format!("| {} |\n", header.join(" | "));
+        table += "| :--- | :--- | :--- | :--- | ---: |\n";
+        for row in rows {
+            table += &format!("| {} |\n", row.join(" | "));
+        }
+        return table;
+    }
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[i].len())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut table = String::new();
+    for row in [&header].into_iter().chain(&rows) {
+        let line = format!(
+            "{:<w0$} {:<w1$} {:<w2$} {:<w3$} {:>w4$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+        table += line.trim_end();
+        table.push('\n');
+    }
+    table
+}
+
+fn cell(answer: Option<&Result<String>>) -> String {
+    match answer {
+        Some(Ok(answer)) => answer.clone(),
+        Some(Err(_)) => "failed".into(),
+        None => "-".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Timings;
+
+    fn outcome(day: usize, answers: std::result::Result<Answers, Failure>) -> Outcome {
+        Outcome {
+            day: &registry::DAYS[day],
+            input: String::new(),
+            answers,
+        }
+    }
+
+    #[test]
+    fn panics_are_caught() {
+        let failure = catch::<()>(|| panic!("day {} fell over", 3)).unwrap_err();
+        assert!(matches!(
+            failure,
+            Failure::Panic(message) if message.starts_with("day 3 fell over at aoc/src/year.rs:")
+        ));
+        assert!(matches!(
+            catch::<()>(|| Err(AocError::NoAnswer("none".into()))),
+            Err(Failure::Error(_))
+        ));
+        assert_eq!(catch(|| Ok(1)).unwrap(), 1);
+    }
+
+    #[test]
+    fn nested_panics_are_caught() {
+        let failure = catch::<()>(|| {
+            // Another day run while this one waits on its rayon tasks.
+            assert_eq!(catch(|| Ok(1)).unwrap(), 1);
+            let task = std::thread::spawn(|| {
+                let _ = panic::catch_unwind(|| panic!("task fell over"));
+                CAUGHT.take()
+            });
+            let caught = task.join().unwrap().unwrap_or_default();
+            assert!(caught.starts_with("task fell over at aoc/src/year.rs:"));
+            panic!("day {} fell over", 5)
+        })
+        .unwrap_err();
+        assert!(matches!(
+            failure,
+            Failure::Panic(message) if message.starts_with("day 5 fell over at aoc/src/year.rs:")
+        ));
+    }
+
+    #[test]
+    fn failed_days_are_in_the_json() {
+        let failed = outcome(1, Err(Failure::Panic("oops".into())));
+        assert_eq!(
+            serde_json::to_value(entry(&failed)).unwrap(),
+            serde_json::json!({
+                "year": 2023,
+                "day": 2,
+                "part1": null,
+                "part2": null,
+                "timings": {"read": null, "parse": null, "part1": null, "part2": null},
+                "error": "panicked: oops",
+            })
+        );
+
+        let solved = outcome(
+            0,
+            Ok(Answers {
+                part_one: Some(Ok("142".into())),
+                ..Answers::default()
+            }),
+        );
+        let json = serde_json::to_value(entry(&solved)).unwrap();
+        assert_eq!(json["part1"], "142");
+        assert!(json.get("error").is_none());
+    }
+
+    #[test]
+    fn summarizes_the_year() {
+        let ms = |n| Some(Duration::from_millis(n));
+        let outcomes = [
+            outcome(
+                0,
+                Ok(Answers {
+                    part_one: Some(Ok("142".into())),
+                    part_two: Some(Err(AocError::NoAnswer("none".into()))),
+                    timings: Timings {
+                        read: ms(1),
+                        parse: ms(2),
+                        part1: ms(3),
+                        part2: ms(4),
+                    },
+                }),
+            ),
+            outcome(1, Err(Failure::Panic("oops".into()))),
+            outcome(
+                2,
+                Ok(Answers {
+                    part_one: Some(Ok("4361".into())),
+                    part_two: None,
+                    timings: Timings {
+                        part1: ms(5),
+                        ..Timings::default()
+                    },
+                }),
+            ),
+        ];
+
+        assert_eq!(
+            table(&outcomes, false),
+            "year  day part 1   part 2   time (ms)\n\
+             2023  1   142      failed      10.000\n\
+             2023  2   panicked panicked         -\n\
+             2023  3   4361     -            5.000\n\
+             total                          15.000\n"
+        );
+        assert_eq!(
+            table(&outcomes[1..], true),
+            "| year | day | part 1 | part 2 | time (ms) |\n\
+             | :--- | :--- | :--- | :--- | ---: |\n\
+             | 2023 | 2 | panicked | panicked | - |\n\
+             | 2023 | 3 | 4361 | - | 5.000 |\n\
+             | total |  |  |  | 5.000 |\n"
+        );
+    }
+}
@@ -0,0 +1,15 @@
+#![no_main]
+
+use day_2023_5::Almanac;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| aoc_fuzz::fuzz_with::<Almanac>(data, few_seeds));
+
+/// Part two tries every seed, so only run it when that's quick.
+fn few_seeds(almanac: &Almanac) -> bool {
+    let seeds = almanac
+        .seeds
+        .iter()
+        .fold(0usize, |sum, &(_, count)| sum.saturating_add(count));
+    seeds <= 10_000
+}
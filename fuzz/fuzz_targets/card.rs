@@ -0,0 +1,7 @@
+#![no_main]
+
+use day_2023_4::Card;
+use libfuzzer_sys::fuzz_target;
+
+// Matches can win copies of cards past the end of the table.
+fuzz_target!(|data: &[u8]| aoc_fuzz::fuzz_streaming::<Card>(data));
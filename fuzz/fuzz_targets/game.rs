@@ -0,0 +1,6 @@
+#![no_main]
+
+use day_2023_2::Game;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| aoc_fuzz::fuzz_streaming::<Game>(data));
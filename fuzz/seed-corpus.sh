@@ -0,0 +1,18 @@
+#!/bin/sh
+# Seed each fuzz target's corpus with its day's examples and real input, so
+# fuzzing starts from well-formed puzzles.
+set -e
+cd "$(dirname "$0")"
+
+seed() {
+    mkdir -p "corpus/$1"
+    for file in ../2023/day-"$2"/test*.txt ../2023/day-"$2"/input.txt; do
+        [ -f "$file" ] && cp "$file" "corpus/$1/$(basename "$file")"
+    done
+}
+
+seed calibration 1
+seed game 2
+seed schematic 3
+seed card 4
+seed almanac 5
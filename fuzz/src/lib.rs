@@ -0,0 +1,43 @@
+//! What each fuzz target does with its input, for any day.
+
+use aoc_common::{diagnostic, input, Solution, Streaming};
+
+/// Normalize and parse the input, then solve both parts if it parses or show
+/// the error if it doesn't. The solvers must fail cleanly on anything that
+/// parses, too.
+pub fn fuzz<S: Solution>(data: &[u8]) {
+    fuzz_with::<S>(data, |_| true);
+}
+
+/// Like [`fuzz`], but only solving part two when `part_two` says it won't
+/// take too long.
+pub fn fuzz_with<S: Solution>(data: &[u8], part_two: impl Fn(&S::Input) -> bool) {
+    let Ok(raw) = std::str::from_utf8(data) else {
+        return;
+    };
+    let input = match input::normalize(raw) {
+        Ok(input) => input,
+        Err(e) => {
+            diagnostic::render(&e, input::without_byte_order_mark(raw));
+            return;
+        }
+    };
+    match S::parse(&input) {
+        Ok(parsed) => {
+            let _ = S::part_one(&parsed);
+            if part_two(&parsed) {
+                let _ = S::part_two(&parsed);
+            }
+        }
+        Err(e) => {
+            diagnostic::render(&e, &input);
+        }
+    }
+}
+
+/// Like [`fuzz`], and also stream the raw input through both parts.
+pub fn fuzz_streaming<S: Streaming>(data: &[u8]) {
+    fuzz::<S>(data);
+    let _ = S::stream_part_one(&mut &data[..]);
+    let _ = S::stream_part_two(&mut &data[..]);
+}